@@ -0,0 +1,253 @@
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::naca::NacaAirfoil;
+use crate::params::{AirfoilSection, FlightParams};
+
+// Tabulated sections ship inside the binary like the built-in presets
+const BUILTIN_AIRFOILS: [(AirfoilSection, &str); 4] = [
+    (AirfoilSection::Dae11, include_str!("../airfoils/dae11.toml")),
+    (AirfoilSection::Fx76Mp140, include_str!("../airfoils/fx76mp140.toml")),
+    (AirfoilSection::Naca0012, include_str!("../airfoils/naca0012.toml")),
+    (AirfoilSection::Naca4412, include_str!("../airfoils/naca4412.toml")),
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PolarPoint {
+    pub alpha: f32,  // deg
+    pub cl: f32,
+    pub cd: f32,
+    pub cm: f32,  // About the quarter chord
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Polar {
+    pub reynolds: f32,
+    pub points: Vec<PolarPoint>,  // Ascending angle of attack
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AirfoilData {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub thickness_ratio: f32,
+    pub polars: Vec<Polar>,  // Ascending Reynolds number
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coordinates: Vec<[f32; 2]>,  // Selig order, when the shape is known
+}
+
+// Section characteristics at one Reynolds number
+#[derive(Clone, Debug, Serialize)]
+pub struct SectionPolar {
+    pub name: String,
+    pub reynolds: f32,
+    pub cl_max: f32,
+    pub alpha_cl_max: f32,  // deg
+    pub cd_min: f32,
+    pub points: Vec<PolarPoint>,  // Empty for a custom section with constant drag
+}
+
+impl AirfoilSection {
+    // Generated NACA sections, including the tabulated ones whose shape follows from the designation
+    pub fn naca(&self) -> Option<NacaAirfoil> {
+        match self {
+            AirfoilSection::Naca(designation) => NacaAirfoil::parse(designation).ok(),
+            AirfoilSection::Naca0012 => NacaAirfoil::parse("0012").ok(),
+            AirfoilSection::Naca4412 => NacaAirfoil::parse("4412").ok(),
+            _ => None,
+        }
+    }
+
+    // Where the section's polars come from, for labelling results
+    pub fn polar_source(&self) -> &'static str {
+        match self {
+            AirfoilSection::Custom => "fixed CLmax and CDmin",
+            AirfoilSection::Naca(_) => "thin-airfoil estimate",
+            AirfoilSection::Imported => "imported polars",
+            _ => "estimated from published section data",
+        }
+    }
+
+    pub fn data(&self) -> Option<&'static AirfoilData> {
+        static DATABASE: OnceLock<Vec<(AirfoilSection, AirfoilData)>> = OnceLock::new();
+        let database = DATABASE.get_or_init(|| {
+            BUILTIN_AIRFOILS
+                .iter()
+                .map(|(section, text)| {
+                    let data = AirfoilData::parse(text).unwrap_or_else(|err| panic!("built-in airfoil {:?}: {}", section, err));
+                    (section.clone(), data)
+                })
+                .collect()
+        });
+        database.iter().find(|(section, _)| section == self).map(|(_, data)| data)
+    }
+}
+
+// Linear interpolation of y(x) over ascending x, held constant beyond the ends
+fn interpolate(x: f32, xs: &[f32], ys: &[f32]) -> f32 {
+    match xs.iter().position(|&value| value >= x) {
+        None => ys[ys.len() - 1],
+        Some(0) => ys[0],
+        Some(index) => {
+            let fraction = (x - xs[index - 1]) / (xs[index] - xs[index - 1]).max(1e-9);
+            ys[index - 1] + (ys[index] - ys[index - 1]) * fraction
+        }
+    }
+}
+
+impl Polar {
+    pub fn at_alpha(&self, alpha: f32) -> PolarPoint {
+        let alphas: Vec<f32> = self.points.iter().map(|point| point.alpha).collect();
+        let column = |value: fn(&PolarPoint) -> f32| {
+            let values: Vec<f32> = self.points.iter().map(value).collect();
+            interpolate(alpha, &alphas, &values)
+        };
+        PolarPoint {
+            alpha,
+            cl: column(|point| point.cl),
+            cd: column(|point| point.cd),
+            cm: column(|point| point.cm),
+        }
+    }
+}
+
+impl AirfoilData {
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        let mut data: AirfoilData = toml::from_str(text)?;
+        data.polars.sort_by(|a, b| a.reynolds.total_cmp(&b.reynolds));
+        Ok(data)
+    }
+
+    pub fn is_usable(&self) -> bool {
+        !self.polars.is_empty() && self.polars.iter().all(|polar| !polar.points.is_empty())
+    }
+
+    // Blend the two bracketing polars linearly in log Re; outside the table the nearest polar is used
+    pub fn at_reynolds(&self, reynolds: f32) -> SectionPolar {
+        let polars = &self.polars;
+        let upper = polars.iter().position(|polar| polar.reynolds >= reynolds).unwrap_or(polars.len() - 1);
+        let lower = upper.saturating_sub(1);
+        let (low, high) = (&polars[lower], &polars[upper]);
+        let fraction = if upper == lower || reynolds <= low.reynolds {
+            0.0
+        } else {
+            ((reynolds.ln() - low.reynolds.ln()) / (high.reynolds.ln() - low.reynolds.ln())).clamp(0.0, 1.0)
+        };
+
+        // Common angle grid over the range both polars cover
+        let start = low.points[0].alpha.max(high.points[0].alpha);
+        let end = low.points[low.points.len() - 1].alpha.min(high.points[high.points.len() - 1].alpha);
+        let mut alphas: Vec<f32> = low.points.iter().chain(&high.points)
+            .map(|point| point.alpha)
+            .filter(|&alpha| alpha >= start && alpha <= end)
+            .collect();
+        alphas.sort_by(f32::total_cmp);
+        alphas.dedup();
+
+        let points: Vec<PolarPoint> = alphas
+            .into_iter()
+            .map(|alpha| {
+                let (a, b) = (low.at_alpha(alpha), high.at_alpha(alpha));
+                PolarPoint {
+                    alpha,
+                    cl: a.cl + (b.cl - a.cl) * fraction,
+                    cd: a.cd + (b.cd - a.cd) * fraction,
+                    cm: a.cm + (b.cm - a.cm) * fraction,
+                }
+            })
+            .collect();
+        SectionPolar::from_points(&self.name, reynolds, points)
+    }
+}
+
+impl SectionPolar {
+    pub fn from_params(params: &FlightParams) -> Self {
+        let reynolds = design_reynolds_number(params);
+        let data = match params.airfoil {
+            AirfoilSection::Imported => params.imported_airfoil.as_ref(),
+            AirfoilSection::Naca(_) => {
+                // No tabulated data: fall back on the thin-airfoil estimate
+                if let Some(naca) = params.airfoil.naca() {
+                    return Self::from_points(&naca.designation, reynolds, naca.estimated_polar(reynolds).points);
+                }
+                None
+            }
+            ref section => section.data(),
+        };
+        match data {
+            Some(data) if data.is_usable() => data.at_reynolds(reynolds),
+            _ => Self::custom(params),
+        }
+    }
+
+    // Slider values: fixed CLmax and a drag coefficient that does not vary with lift
+    pub fn custom(params: &FlightParams) -> Self {
+        Self {
+            name: "Custom".to_string(),
+            reynolds: design_reynolds_number(params),
+            cl_max: params.airfoil_cl_max,
+            alpha_cl_max: 0.0,
+            cd_min: params.airfoil_cd_min,
+            points: Vec::new(),
+        }
+    }
+
+    pub fn from_points(name: &str, reynolds: f32, points: Vec<PolarPoint>) -> Self {
+        let stall = points.iter().copied().max_by(|a, b| a.cl.total_cmp(&b.cl)).unwrap_or_default();
+        let cd_min = points.iter().map(|point| point.cd).reduce(f32::min).unwrap_or(0.0);
+        Self {
+            name: name.to_string(),
+            reynolds,
+            cl_max: stall.cl,
+            alpha_cl_max: stall.alpha,
+            cd_min,
+            points,
+        }
+    }
+
+    // Attached-flow branch of the polar, up to CLmax
+    fn attached_points(&self) -> impl Iterator<Item = &PolarPoint> {
+        self.points.iter().filter(move |point| point.alpha <= self.alpha_cl_max)
+    }
+
+    // Section drag at a given lift coefficient; beyond CLmax the stall value is held
+    pub fn profile_drag(&self, cl: f32) -> f32 {
+        if self.points.is_empty() {
+            return self.cd_min;
+        }
+        let (cls, cds): (Vec<f32>, Vec<f32>) = self.attached_points().map(|point| (point.cl, point.cd)).unzip();
+        interpolate(cl, &cls, &cds)
+    }
+
+    pub fn moment_coefficient(&self, cl: f32) -> f32 {
+        if self.points.is_empty() {
+            return 0.0;
+        }
+        let (cls, cms): (Vec<f32>, Vec<f32>) = self.attached_points().map(|point| (point.cl, point.cm)).unzip();
+        interpolate(cl, &cls, &cms)
+    }
+}
+
+// Thickness implied by the selected section, None for a custom section
+pub fn section_thickness_ratio(params: &FlightParams) -> Option<f32> {
+    match &params.airfoil {
+        AirfoilSection::Imported => params.imported_airfoil.as_ref().map(|data| data.thickness_ratio),
+        section => section.naca().map(|naca| naca.thickness_ratio).or_else(|| section.data().map(|data| data.thickness_ratio)),
+    }
+}
+
+// Unit-chord outline in Selig order, empty when the shape is unknown
+pub fn section_coordinates(params: &FlightParams) -> Vec<[f32; 2]> {
+    match &params.airfoil {
+        AirfoilSection::Imported => params.imported_airfoil.as_ref().map(|data| data.coordinates.clone()).unwrap_or_default(),
+        section => section.naca().map(|naca| naca.coordinates()).unwrap_or_default(),
+    }
+}
+
+// Reynolds number on the mean aerodynamic chord at the configured airspeed
+pub fn design_reynolds_number(params: &FlightParams) -> f32 {
+    let airspeed = (params.forward_speed - params.wind_speed).max(0.0);
+    airspeed * params.planform().mean_aerodynamic_chord() / params.atmosphere().kinematic_viscosity
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::airfoil::{AirfoilData, Polar, PolarPoint};
+
+const THICKNESS_STATIONS: usize = 200;
+
+#[derive(Debug)]
+pub enum ImportError {
+    Io(std::io::Error),
+    Format(String),
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Io(err) => write!(f, "I/O error: {}", err),
+            ImportError::Format(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+impl From<std::io::Error> for ImportError {
+    fn from(err: std::io::Error) -> Self {
+        ImportError::Io(err)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AirfoilCoordinates {
+    pub name: String,
+    pub points: Vec<[f32; 2]>,  // Selig order: trailing edge, upper surface, leading edge, lower surface
+}
+
+// Two numbers per line; anything else ends the block or is rejected by the caller
+fn parse_pair(line: &str) -> Option<[f32; 2]> {
+    let mut values = line.split_whitespace().map(|value| value.parse::<f32>());
+    match (values.next(), values.next(), values.next()) {
+        (Some(Ok(x)), Some(Ok(y)), None) => Some([x, y]),
+        _ => None,
+    }
+}
+
+// Selig files run around the section from the trailing edge; Lednicer files give the point
+// counts on the second line followed by the upper and lower surfaces, each from the leading edge
+pub fn parse_coordinates(text: &str) -> Result<AirfoilCoordinates, ImportError> {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    let name = lines.next().ok_or_else(|| ImportError::Format("empty coordinate file".to_string()))?.to_string();
+    let rows: Vec<&str> = lines.collect();
+
+    let pairs = rows
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            parse_pair(line).ok_or_else(|| ImportError::Format(format!("line {}: expected two numbers, found '{}'", index + 2, line)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let points = match pairs.first() {
+        Some(&[upper_count, lower_count]) if upper_count >= 2.0 && lower_count >= 2.0 => {
+            let (upper_count, lower_count) = (upper_count as usize, lower_count as usize);
+            let surfaces = &pairs[1..];
+            if surfaces.len() != upper_count + lower_count {
+                return Err(ImportError::Format(format!(
+                    "Lednicer header lists {} + {} points but the file has {}",
+                    upper_count, lower_count, surfaces.len()
+                )));
+            }
+            let (upper, lower) = surfaces.split_at(upper_count);
+            upper.iter().rev().chain(lower.iter().skip(1)).copied().collect()
+        }
+        _ => pairs,
+    };
+
+    if points.len() < 5 {
+        return Err(ImportError::Format("too few coordinate points".to_string()));
+    }
+    Ok(AirfoilCoordinates { name, points })
+}
+
+impl AirfoilCoordinates {
+    // Upper and lower surfaces as ascending-x point lists, split at the leading edge
+    fn surfaces(&self) -> (Vec<[f32; 2]>, Vec<[f32; 2]>) {
+        let leading_edge = self
+            .points
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1[0].total_cmp(&b.1[0]))
+            .map_or(0, |(index, _)| index);
+        let mut upper: Vec<[f32; 2]> = self.points[..=leading_edge].to_vec();
+        let mut lower: Vec<[f32; 2]> = self.points[leading_edge..].to_vec();
+        upper.sort_by(|a, b| a[0].total_cmp(&b[0]));
+        lower.sort_by(|a, b| a[0].total_cmp(&b[0]));
+        (upper, lower)
+    }
+
+    fn surface_height(surface: &[[f32; 2]], x: f32) -> f32 {
+        match surface.iter().position(|point| point[0] >= x) {
+            None => surface[surface.len() - 1][1],
+            Some(0) => surface[0][1],
+            Some(index) => {
+                let (a, b) = (surface[index - 1], surface[index]);
+                a[1] + (b[1] - a[1]) * (x - a[0]) / (b[0] - a[0]).max(1e-9)
+            }
+        }
+    }
+
+    // Maximum thickness and maximum camber as fractions of the chord
+    pub fn section_properties(&self) -> (f32, f32) {
+        let (upper, lower) = self.surfaces();
+        let start = self.points.iter().map(|point| point[0]).fold(f32::INFINITY, f32::min);
+        let end = self.points.iter().map(|point| point[0]).fold(f32::NEG_INFINITY, f32::max);
+        let chord = (end - start).max(1e-6);
+
+        let (mut thickness, mut camber) = (0.0f32, 0.0f32);
+        for i in 0..=THICKNESS_STATIONS {
+            let x = start + chord * i as f32 / THICKNESS_STATIONS as f32;
+            let (y_upper, y_lower) = (Self::surface_height(&upper, x), Self::surface_height(&lower, x));
+            thickness = thickness.max((y_upper - y_lower).abs());
+            let mean_line = 0.5 * (y_upper + y_lower);
+            if mean_line.abs() > camber.abs() {
+                camber = mean_line;
+            }
+        }
+        (thickness / chord, camber / chord)
+    }
+
+    pub fn thickness_ratio(&self) -> f32 {
+        self.section_properties().0
+    }
+}
+
+// "Re =     0.500 e 6", "Re = 500000" and "Re = 5.0e5" all appear in the wild
+fn parse_reynolds(line: &str) -> Option<f32> {
+    let after = &line[line.find("Re =")? + 4..];
+    let tokens: Vec<&str> = after.split_whitespace().collect();
+    let mantissa: f32 = tokens.first()?.parse().ok()?;
+    match (tokens.get(1), tokens.get(2)) {
+        (Some(&"e"), Some(exponent)) => exponent.parse::<i32>().ok().map(|exponent| mantissa * 10f32.powi(exponent)),
+        _ => Some(mantissa),
+    }
+}
+
+// XFOIL polar save file (PACC output); returns the section name and the polar
+pub fn parse_xfoil_polar(text: &str) -> Result<(String, Polar), ImportError> {
+    let mut name = String::new();
+    let mut reynolds = None;
+    let mut columns: Option<Vec<String>> = None;
+    let mut points = Vec::new();
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("Calculated polar for:") {
+            name = rest.trim().to_string();
+        } else if trimmed.contains("Re =") {
+            reynolds = parse_reynolds(trimmed);
+        } else if trimmed.starts_with("alpha") {
+            columns = Some(trimmed.split_whitespace().map(str::to_ascii_lowercase).collect());
+        } else if let Some(columns) = &columns {
+            if trimmed.is_empty() || trimmed.starts_with("---") {
+                continue;
+            }
+            let values: Vec<f32> = trimmed
+                .split_whitespace()
+                .map(|value| value.parse::<f32>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| ImportError::Format(format!("unreadable polar row '{}'", trimmed)))?;
+            let column = |label: &str| {
+                columns
+                    .iter()
+                    .position(|column| column == label)
+                    .and_then(|index| values.get(index).copied())
+                    .ok_or_else(|| ImportError::Format(format!("polar row '{}' has no {} value", trimmed, label)))
+            };
+            points.push(PolarPoint {
+                alpha: column("alpha")?,
+                cl: column("cl")?,
+                cd: column("cd")?,
+                cm: column("cm")?,
+            });
+        }
+    }
+
+    let reynolds = reynolds.filter(|re| *re > 0.0)
+        .ok_or_else(|| ImportError::Format("polar has no Reynolds number (fixed-Re XFOIL polar expected)".to_string()))?;
+    if points.len() < 3 {
+        return Err(ImportError::Format("polar has fewer than three converged points".to_string()));
+    }
+    points.sort_by(|a, b| a.alpha.total_cmp(&b.alpha));
+    points.dedup_by(|a, b| a.alpha == b.alpha);
+    Ok((name, Polar { reynolds, points }))
+}
+
+pub fn load_coordinates(path: &Path) -> Result<AirfoilCoordinates, ImportError> {
+    parse_coordinates(&fs::read_to_string(path)?)
+}
+
+pub fn load_xfoil_polar(path: &Path) -> Result<(String, Polar), ImportError> {
+    parse_xfoil_polar(&fs::read_to_string(path)?)
+}
+
+// Coordinates give the name, shape and thickness; one polar per Reynolds number gives the aerodynamics
+pub fn import_airfoil(coordinates_path: &Path, polar_paths: &[PathBuf]) -> Result<AirfoilData, ImportError> {
+    let coordinates = load_coordinates(coordinates_path)?;
+    if polar_paths.is_empty() {
+        return Err(ImportError::Format("at least one XFOIL polar is needed".to_string()));
+    }
+    let mut polars = polar_paths
+        .iter()
+        .map(|path| {
+            load_xfoil_polar(path)
+                .map(|(_, polar)| polar)
+                .map_err(|err| ImportError::Format(format!("{}: {}", path.display(), err)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    polars.sort_by(|a, b| a.reynolds.total_cmp(&b.reynolds));
+
+    Ok(AirfoilData {
+        name: coordinates.name.clone(),
+        description: format!("Imported from {}", coordinates_path.display()),
+        thickness_ratio: coordinates.thickness_ratio(),
+        polars,
+        coordinates: coordinates.points,
+    })
+}
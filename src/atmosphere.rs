@@ -0,0 +1,100 @@
+use serde::Serialize;
+
+// International Standard Atmosphere (ISO 2533) sea-level constants
+pub const SEA_LEVEL_TEMPERATURE: f32 = 288.15;  // K
+pub const SEA_LEVEL_PRESSURE: f32 = 101_325.0;  // Pa
+pub const TROPOSPHERE_LAPSE_RATE: f32 = 0.0065;  // K/m
+pub const TROPOPAUSE_ALTITUDE: f32 = 11_000.0;  // m
+pub const MAX_ALTITUDE: f32 = 20_000.0;  // m, top of the isothermal layer modelled here
+
+const GRAVITY: f32 = 9.80665;  // m/s², standard gravity used by the ISA
+const GAS_CONSTANT_DRY_AIR: f32 = 287.058;  // J/(kg·K)
+const GAS_CONSTANT_WATER_VAPOUR: f32 = 461.495;  // J/(kg·K)
+const HEAT_CAPACITY_RATIO: f32 = 1.4;
+
+// Sutherland's law constants for air
+const SUTHERLAND_C1: f32 = 1.458e-6;  // kg/(m·s·K^0.5)
+const SUTHERLAND_S: f32 = 110.4;  // K
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Atmosphere {
+    pub altitude: f32,  // m
+    pub temperature: f32,  // K
+    pub pressure: f32,  // Pa
+    pub vapour_pressure: f32,  // Pa
+    pub density: f32,  // kg/m³
+    pub speed_of_sound: f32,  // m/s
+    pub dynamic_viscosity: f32,  // Pa·s
+    pub kinematic_viscosity: f32,  // m²/s
+}
+
+impl Atmosphere {
+    // ISA+ΔT convention: the offset shifts temperature but pressure follows the standard profile.
+    // Humidity is relative humidity in [0, 1]; moist air is lighter than dry air at equal pressure.
+    pub fn isa(altitude: f32, temperature_offset: f32, relative_humidity: f32) -> Self {
+        let altitude = altitude.clamp(0.0, MAX_ALTITUDE);
+        let relative_humidity = relative_humidity.clamp(0.0, 1.0);
+
+        let (standard_temperature, pressure) = standard_temperature_and_pressure(altitude);
+        let temperature = (standard_temperature + temperature_offset).max(150.0);
+
+        // Partial pressure of water vapour from the saturation pressure (Buck equation)
+        let vapour_pressure = (relative_humidity * saturation_vapour_pressure(temperature)).min(pressure * 0.5);
+        let dry_pressure = pressure - vapour_pressure;
+        let density = dry_pressure / (GAS_CONSTANT_DRY_AIR * temperature)
+            + vapour_pressure / (GAS_CONSTANT_WATER_VAPOUR * temperature);
+
+        // Virtual temperature folds the vapour into an equivalent dry-air temperature
+        let virtual_temperature = temperature / (1.0 - (vapour_pressure / pressure) * (1.0 - 0.622));
+        let speed_of_sound = (HEAT_CAPACITY_RATIO * GAS_CONSTANT_DRY_AIR * virtual_temperature).sqrt();
+
+        let dynamic_viscosity = SUTHERLAND_C1 * temperature.powf(1.5) / (temperature + SUTHERLAND_S);
+        let kinematic_viscosity = dynamic_viscosity / density;
+
+        Self {
+            altitude,
+            temperature,
+            pressure,
+            vapour_pressure,
+            density,
+            speed_of_sound,
+            dynamic_viscosity,
+            kinematic_viscosity,
+        }
+    }
+
+    pub fn sea_level() -> Self {
+        Self::isa(0.0, 0.0, 0.0)
+    }
+
+    pub fn temperature_celsius(&self) -> f32 {
+        self.temperature - 273.15
+    }
+
+    pub fn density_ratio(&self) -> f32 {
+        self.density / Self::sea_level().density
+    }
+}
+
+fn standard_temperature_and_pressure(altitude: f32) -> (f32, f32) {
+    let exponent = GRAVITY / (GAS_CONSTANT_DRY_AIR * TROPOSPHERE_LAPSE_RATE);
+
+    if altitude <= TROPOPAUSE_ALTITUDE {
+        let temperature = SEA_LEVEL_TEMPERATURE - TROPOSPHERE_LAPSE_RATE * altitude;
+        let pressure = SEA_LEVEL_PRESSURE * (temperature / SEA_LEVEL_TEMPERATURE).powf(exponent);
+        (temperature, pressure)
+    } else {
+        // Isothermal lower stratosphere
+        let tropopause_temperature = SEA_LEVEL_TEMPERATURE - TROPOSPHERE_LAPSE_RATE * TROPOPAUSE_ALTITUDE;
+        let tropopause_pressure = SEA_LEVEL_PRESSURE * (tropopause_temperature / SEA_LEVEL_TEMPERATURE).powf(exponent);
+        let pressure = tropopause_pressure
+            * (-GRAVITY * (altitude - TROPOPAUSE_ALTITUDE) / (GAS_CONSTANT_DRY_AIR * tropopause_temperature)).exp();
+        (tropopause_temperature, pressure)
+    }
+}
+
+// Buck (1981) saturation vapour pressure over water, Pa
+fn saturation_vapour_pressure(temperature: f32) -> f32 {
+    let celsius = temperature - 273.15;
+    611.21 * ((18.678 - celsius / 234.5) * (celsius / (257.14 + celsius))).exp()
+}
@@ -0,0 +1,212 @@
+use serde::Serialize;
+
+use crate::params::{BatteryChemistry, FlightParams};
+
+pub struct ChemistryProperties {
+    pub specific_energy: f32,  // Wh/kg at pack level, including wiring and packaging
+    pub nominal_cell_voltage: f32,  // V
+    pub specific_resistance: f32,  // Ω·Ah per cell, internal resistance scales inversely with capacity
+    pub peukert_exponent: f32,
+    // Open-circuit cell voltage at 0, 10, 50, 90 and 100% state of charge
+    pub discharge_curve: [f32; 5],
+}
+
+const DISCHARGE_CURVE_SOC: [f32; 5] = [0.0, 0.1, 0.5, 0.9, 1.0];
+
+impl BatteryChemistry {
+    pub fn properties(&self) -> ChemistryProperties {
+        match self {
+            BatteryChemistry::LiPo => ChemistryProperties {
+                specific_energy: 180.0,
+                nominal_cell_voltage: 3.7,
+                specific_resistance: 0.02,
+                peukert_exponent: 1.05,
+                discharge_curve: [3.3, 3.6, 3.75, 3.95, 4.2],
+            },
+            BatteryChemistry::LiIon => ChemistryProperties {
+                specific_energy: 220.0,
+                nominal_cell_voltage: 3.6,
+                specific_resistance: 0.09,
+                peukert_exponent: 1.08,
+                discharge_curve: [3.0, 3.4, 3.65, 3.95, 4.2],
+            },
+            BatteryChemistry::LiFePO4 => ChemistryProperties {
+                specific_energy: 110.0,
+                nominal_cell_voltage: 3.2,
+                specific_resistance: 0.03,
+                peukert_exponent: 1.03,
+                discharge_curve: [2.5, 3.1, 3.25, 3.3, 3.6],
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct BatteryLoad {
+    pub current: f32,  // A
+    pub terminal_voltage: f32,  // V
+    pub voltage_sag: f32,  // V below open circuit
+    pub c_rate: f32,
+    pub resistive_loss: f32,  // W dissipated inside the pack
+    pub power: f32,  // W delivered, less than requested when the pack cannot supply it
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BatteryAnalysis {
+    pub mass: f32,  // kg
+    pub nominal_voltage: f32,  // V
+    pub capacity_ah: f32,
+    pub usable_energy: f32,  // Wh within the depth-of-discharge limit
+    pub internal_resistance: f32,  // Ω
+    pub load: BatteryLoad,  // At the cruise motor draw, full charge
+    pub endurance: f32,  // min at the cruise motor draw
+}
+
+#[derive(Clone, Debug)]
+pub struct Battery {
+    pub chemistry: BatteryChemistry,
+    pub capacity: f32,  // Wh nominal
+    pub depth_of_discharge: f32,  // Usable fraction of capacity
+    pub series_cells: u32,
+}
+
+impl Battery {
+    pub fn from_params(params: &FlightParams) -> Self {
+        Self {
+            chemistry: params.battery_chemistry.clone(),
+            capacity: if params.motor_power > 0.0 { params.battery_capacity.max(0.0) } else { 0.0 },  // No motor, no pack to carry
+            depth_of_discharge: params.battery_depth_of_discharge.clamp(0.0, 1.0),
+            series_cells: params.battery_series_cells.max(1),
+        }
+    }
+
+    pub fn mass(&self) -> f32 {
+        self.capacity / self.chemistry.properties().specific_energy
+    }
+
+    pub fn nominal_voltage(&self) -> f32 {
+        self.series_cells as f32 * self.chemistry.properties().nominal_cell_voltage
+    }
+
+    pub fn capacity_ah(&self) -> f32 {
+        self.capacity / self.nominal_voltage()
+    }
+
+    // Zero for an empty pack, which has no cells to resist
+    pub fn internal_resistance(&self) -> f32 {
+        let capacity_ah = self.capacity_ah();
+        if capacity_ah > 0.0 {
+            self.series_cells as f32 * self.chemistry.properties().specific_resistance / capacity_ah
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.capacity <= 0.0
+    }
+
+    // Charge left when the depth-of-discharge limit is reached
+    pub fn minimum_state_of_charge(&self) -> f32 {
+        1.0 - self.depth_of_discharge
+    }
+
+    pub fn open_circuit_voltage(&self, state_of_charge: f32) -> f32 {
+        let curve = self.chemistry.properties().discharge_curve;
+        let soc = state_of_charge.clamp(0.0, 1.0);
+        let segment = DISCHARGE_CURVE_SOC
+            .windows(2)
+            .position(|pair| soc <= pair[1])
+            .unwrap_or(DISCHARGE_CURVE_SOC.len() - 2);
+        let (soc_low, soc_high) = (DISCHARGE_CURVE_SOC[segment], DISCHARGE_CURVE_SOC[segment + 1]);
+        let fraction = (soc - soc_low) / (soc_high - soc_low);
+        let cell_voltage = curve[segment] + (curve[segment + 1] - curve[segment]) * fraction;
+        self.series_cells as f32 * cell_voltage
+    }
+
+    // Largest power the pack can deliver, reached when the sag equals half the open-circuit voltage
+    pub fn max_power(&self, state_of_charge: f32) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        self.open_circuit_voltage(state_of_charge).powi(2) / (4.0 * self.internal_resistance())
+    }
+
+    // Terminal voltage under load from P = V_oc·I - I²R
+    pub fn load(&self, state_of_charge: f32, power: f32) -> BatteryLoad {
+        if power <= 0.0 || self.is_empty() {
+            return BatteryLoad {
+                terminal_voltage: self.open_circuit_voltage(state_of_charge),
+                ..Default::default()
+            };
+        }
+        let open_circuit = self.open_circuit_voltage(state_of_charge);
+        let resistance = self.internal_resistance();
+        let power = power.min(self.max_power(state_of_charge));
+        let current = (open_circuit - (open_circuit.powi(2) - 4.0 * resistance * power).max(0.0).sqrt()) / (2.0 * resistance);
+        let voltage_sag = current * resistance;
+        BatteryLoad {
+            current,
+            terminal_voltage: open_circuit - voltage_sag,
+            voltage_sag,
+            c_rate: current / self.capacity_ah(),
+            resistive_loss: current * voltage_sag,
+            power,
+        }
+    }
+
+    // Peukert's law relative to the 1C rating: capacity is drawn faster than the current alone suggests
+    pub fn effective_current(&self, current: f32) -> f32 {
+        let rated_current = self.capacity_ah();
+        if current <= rated_current || rated_current <= 0.0 {
+            return current;
+        }
+        current * (current / rated_current).powf(self.chemistry.properties().peukert_exponent - 1.0)
+    }
+
+    // Change in state of charge after delivering `power` for `dt` seconds
+    pub fn discharge(&self, state_of_charge: f32, power: f32, dt: f32) -> (f32, BatteryLoad) {
+        let load = self.load(state_of_charge, power);
+        let charge_used = self.effective_current(load.current) * dt / 3600.0;  // Ah
+        let state_of_charge = (state_of_charge - charge_used / self.capacity_ah().max(1e-6)).max(0.0);
+        (state_of_charge, load)
+    }
+
+    // Minutes at constant power from full charge to the depth-of-discharge limit
+    pub fn endurance(&self, power: f32) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        if power <= 0.0 || self.depth_of_discharge <= 0.0 {
+            return f32::INFINITY;
+        }
+        if power > self.max_power(1.0) {
+            return 0.0;
+        }
+
+        let steps = 100;
+        let soc_step = self.depth_of_discharge / steps as f32;
+        let mut time = 0.0;
+        for i in 0..steps {
+            let soc = 1.0 - (i as f32 + 0.5) * soc_step;
+            let load = self.load(soc, power);
+            if load.power < power {
+                break;  // Voltage has sagged too far to hold the power
+            }
+            time += soc_step * self.capacity_ah() / self.effective_current(load.current).max(1e-6) * 3600.0;
+        }
+        time / 60.0
+    }
+
+    pub fn analyze(&self, power: f32) -> BatteryAnalysis {
+        BatteryAnalysis {
+            mass: self.mass(),
+            nominal_voltage: self.nominal_voltage(),
+            capacity_ah: self.capacity_ah(),
+            usable_energy: self.capacity * self.depth_of_discharge,
+            internal_resistance: self.internal_resistance(),
+            load: self.load(1.0, power),
+            endurance: self.endurance(power),
+        }
+    }
+}
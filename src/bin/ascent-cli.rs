@@ -0,0 +1,539 @@
+use ascent::airfoil_import::import_airfoil;
+use ascent::config::load_params;
+use ascent::{
+    calculate_comprehensive_flight_analysis, calculate_speed_sweep, simulate_mission, FlappingSample, FlightAnalysis, FlightParams,
+    MaterialLibrary, MissionProfile, MissionResult, NacaAirfoil, PresetLibrary, Propeller, SpeedSweep,
+};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: ascent-cli <command> <config.toml|config.json> [--format text|json]
+       ascent-cli <command> --preset <name> [--format text|json]
+
+Commands:
+  analyze  Evaluate the configuration and print the full flight and structural analysis
+  sweep    Print power required and L/D across the airspeed range with V_min_power,
+           V_max_L/D and V_max
+  simulate Fly a full mission (ground roll, climb, cruise, descent, landing) with the
+           time-stepped point-mass simulation
+
+Options:
+  --airfoil <file.dat>   Replace the wing section with Selig or Lednicer coordinates;
+                         needs at least one --polar
+  --polar <file>         XFOIL polar save file for the imported section, repeat for
+                         each Reynolds number
+  --naca <designation>   Generate a NACA 4- or 5-digit section (e.g. 2412, 23012) and
+                         estimate its polar from thin-airfoil theory
+
+Exits with status 1 when the configuration cannot sustain level flight and 2 on
+usage or configuration errors.";
+
+#[derive(Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+enum ConfigSource {
+    File(PathBuf),
+    Preset(String),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Command {
+    Analyze,
+    Sweep,
+    Simulate,
+}
+
+struct CliArgs {
+    command: Command,
+    source: ConfigSource,
+    format: OutputFormat,
+    airfoil: Option<PathBuf>,
+    polars: Vec<PathBuf>,
+    naca: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut iter = args.iter();
+    let command = match iter.next().map(String::as_str) {
+        Some("analyze") => Command::Analyze,
+        Some("sweep") => Command::Sweep,
+        Some("simulate") => Command::Simulate,
+        Some(other) => return Err(format!("unknown command '{}'", other)),
+        None => return Err("missing command".to_string()),
+    };
+
+    let mut source = None;
+    let mut format = OutputFormat::Text;
+    let mut airfoil = None;
+    let mut polars = Vec::new();
+    let mut naca = None;
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                format = match iter.next().map(String::as_str) {
+                    Some("text") => OutputFormat::Text,
+                    Some("json") => OutputFormat::Json,
+                    Some(other) => return Err(format!("unknown format '{}'", other)),
+                    None => return Err("--format needs a value".to_string()),
+                };
+            }
+            "--json" => format = OutputFormat::Json,
+            "--airfoil" => {
+                let path = iter.next().ok_or_else(|| "--airfoil needs a coordinate file".to_string())?;
+                airfoil = Some(PathBuf::from(path));
+            }
+            "--polar" => {
+                let path = iter.next().ok_or_else(|| "--polar needs a polar file".to_string())?;
+                polars.push(PathBuf::from(path));
+            }
+            "--naca" => {
+                let designation = iter.next().ok_or_else(|| "--naca needs a designation".to_string())?;
+                NacaAirfoil::parse(designation)?;
+                naca = Some(designation.clone());
+            }
+            "--preset" | "-p" if source.is_none() => {
+                let name = iter.next().ok_or_else(|| "--preset needs a name".to_string())?;
+                source = Some(ConfigSource::Preset(name.clone()));
+            }
+            _ if source.is_none() => source = Some(ConfigSource::File(PathBuf::from(arg))),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    let source = source.ok_or_else(|| "missing configuration file or --preset".to_string())?;
+    if airfoil.is_some() == polars.is_empty() {
+        return Err("--airfoil and --polar must be given together".to_string());
+    }
+    if airfoil.is_some() && naca.is_some() {
+        return Err("--naca cannot be combined with --airfoil".to_string());
+    }
+    Ok(CliArgs { command, source, format, airfoil, polars, naca })
+}
+
+fn load_source(source: &ConfigSource) -> Result<FlightParams, String> {
+    let materials = MaterialLibrary::load();
+    match source {
+        ConfigSource::File(path) => {
+            load_params(path, &materials).map_err(|err| format!("failed to load {}: {}", path.display(), err))
+        }
+        ConfigSource::Preset(name) => {
+            let library = PresetLibrary::load(&materials);
+            library.get(name).map(|preset| preset.params.clone()).ok_or_else(|| {
+                let names: Vec<&str> = library.presets.iter().map(|preset| preset.name.as_str()).collect();
+                format!("unknown preset '{}' (available: {})", name, names.join(", "))
+            })
+        }
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "YES" } else { "NO" }
+}
+
+fn print_text_report(params: &FlightParams, analysis: &FlightAnalysis) {
+    let structural = &analysis.structural;
+
+    println!("Basic Properties");
+    println!("  Total Mass:              {:.1} kg", analysis.total_mass);
+    println!("  Weight:                  {:.0} N", analysis.weight_force);
+    println!("  Wing Area:               {:.2} m²", analysis.wing_area);
+    println!("  Wing Loading:            {:.1} N/m²", analysis.wing_loading);
+    println!("  Stall Speed:             {:.2} m/s", analysis.stall_speed);
+    println!();
+
+    let planform = &analysis.planform;
+    println!("Planform");
+    println!("  Panels per Wing:         {}", planform.panels.len());
+    println!("  Span (root to tip):      {:.2} m", planform.span());
+    println!("  Root / Tip Chord:        {:.2} / {:.2} m", planform.root_chord(), planform.tip_chord());
+    println!("  Mean Aerodynamic Chord:  {:.3} m", planform.mean_aerodynamic_chord());
+    for (index, panel) in planform.panels.iter().enumerate().filter(|_| planform.panels.len() > 1) {
+        println!("  Panel {}:                 {:.2} m, {:.2}→{:.2} m chord, {:.0}° sweep, {:.0}° dihedral, {:.1}° washout",
+            index + 1, panel.span, panel.root_chord, panel.tip_chord, panel.sweep, panel.dihedral, panel.twist);
+    }
+    println!();
+
+    let atmosphere = &analysis.atmosphere;
+    println!("Atmosphere");
+    println!("  Altitude:                {:.0} m", atmosphere.altitude);
+    println!("  Temperature:             {:.1} K ({:.1} °C)", atmosphere.temperature, atmosphere.temperature_celsius());
+    println!("  Pressure:                {:.0} Pa", atmosphere.pressure);
+    println!("  Vapour Pressure:         {:.0} Pa", atmosphere.vapour_pressure);
+    println!("  Density:                 {:.4} kg/m³", atmosphere.density);
+    println!("  Speed of Sound:          {:.1} m/s", atmosphere.speed_of_sound);
+    println!("  Dynamic Viscosity:       {:.3e} Pa·s", atmosphere.dynamic_viscosity);
+    println!("  Kinematic Viscosity:     {:.3e} m²/s", atmosphere.kinematic_viscosity);
+    println!();
+
+    let airfoil = &analysis.airfoil;
+    println!("Airfoil");
+    println!("  Section:                 {}", airfoil.name);
+    println!("  Polars:                  {}", params.airfoil.polar_source());
+    println!("  Reynolds Number:         {:.0}", airfoil.reynolds);
+    if airfoil.points.is_empty() {
+        println!("  CLmax:                   {:.3}", airfoil.cl_max);
+    } else {
+        println!("  CLmax:                   {:.3} at {:.1}°", airfoil.cl_max, airfoil.alpha_cl_max);
+    }
+    println!("  CDmin:                   {:.5}", airfoil.cd_min);
+    if let Some(theory) = &analysis.thin_airfoil {
+        println!("  Zero-Lift Angle:         {:.2}° (thin-airfoil theory)", theory.zero_lift_angle);
+        println!("  Lift Slope:              {:.3} /rad", theory.lift_slope);
+        println!("  Ideal CL:                {:.3}", theory.ideal_lift_coefficient);
+        println!("  Cm (c/4):                {:.4}", theory.moment_coefficient);
+    }
+    println!();
+
+    let lift_curve = &analysis.lift_curve;
+    println!("Lift Curve");
+    println!("  Zero-Lift Angle:         {:.2}°", lift_curve.zero_lift_angle);
+    println!("  Section Lift Slope:      {:.3} /rad", lift_curve.section_lift_slope);
+    println!("  Wing Lift Slope:         {:.3} /rad", lift_curve.lift_slope);
+    println!("  Stall Angle:             {:.1}° (CL {:.3})", lift_curve.stall_angle, lift_curve.cl_max);
+    println!("  Post-Stall Lift Loss:    {:.3} per °", lift_curve.post_stall_slope);
+    println!();
+
+    let span_loading = &analysis.span_loading;
+    println!("Span Loading");
+    println!("  Lifting Surfaces:        {}", span_loading.planform.surfaces.len());
+    if span_loading.planform.surfaces.len() > 1 {
+        println!("  Layout:                  {:?}", analysis.wing_layout);
+    }
+    println!("  Reference Span:          {:.2} m", span_loading.reference_span);
+    println!("  Aspect Ratio:            {:.2} (effective {:.2})", span_loading.aspect_ratio, span_loading.effective_aspect_ratio());
+    println!("  Span Efficiency:         {:.3}", analysis.span_efficiency);
+    println!("  Induced Drag Coeff:      {:.4}", analysis.induced_drag_coefficient);
+    let shares = span_loading.surface_lift_fractions(analysis.current_lift_coefficient);
+    if shares.len() > 1 {
+        let munk = &analysis.munk_interference;
+        let format_shares = |shares: &[f32]| shares.iter().map(|share| format!("{:.1}%", share * 100.0)).collect::<Vec<_>>().join(" / ");
+        println!("  Lift Share:              {}", format_shares(&shares));
+        println!("  Munk Interference σ12:   {:.3}", munk.interference[0][1]);
+        println!("  Munk Optimum Share:      {} (e {:.3})", format_shares(&munk.optimal_lift_shares), munk.minimum_span_efficiency);
+    }
+    println!();
+
+    let parasite = &analysis.parasite_drag;
+    println!("Parasite Drag");
+    for component in &parasite.components {
+        if component.wetted_area > 0.0 {
+            println!("  {:<24} {:.4} m² (S_wet {:.2} m², FF {:.2})", format!("{}:", component.name), component.drag_area,
+                component.wetted_area, component.form_factor);
+        } else {
+            println!("  {:<24} {:.4} m²", format!("{}:", component.name), component.drag_area);
+        }
+    }
+    println!("  Total Drag Area:         {:.4} m²", parasite.drag_area);
+    println!("  CD0 (airframe):          {:.4}", parasite.drag_coefficient);
+    println!();
+
+    println!("Flight State");
+    println!("  Phase:                   {:?}", analysis.flight_phase);
+    println!("  Airspeed:                {:.2} m/s", analysis.effective_airspeed);
+    println!("  Dynamic Pressure:        {:.1} Pa", analysis.dynamic_pressure);
+    println!("  Angle of Attack:         {:.2}°", analysis.angle_of_attack);
+    println!("  Stall Margin:            {:.2}°", analysis.stall_margin);
+    println!("  Lift Coefficient:        {:.3}", analysis.current_lift_coefficient);
+    println!("  Drag Coefficient:        {:.4}", analysis.current_drag_coefficient);
+    println!("  Lift:                    {:.0} N", analysis.lift_force);
+    println!("  Drag:                    {:.1} N", analysis.drag_force);
+    println!("  Reynolds Number:         {:.0}", analysis.reynolds_number);
+    println!();
+
+    println!("Power");
+    println!("  Drag Power:              {:.0} W", analysis.power_to_overcome_drag);
+    println!("    Wing:                  {:.0} W", analysis.power_to_overcome_drag - analysis.power_for_parasite_drag);
+    println!("    Parasite:              {:.0} W", analysis.power_for_parasite_drag);
+    println!("  Propeller Shaft Power:   {:.0} W", analysis.propeller.shaft_power);
+    println!("  Flapping Power:          {:.0} W", analysis.power_for_flapping);
+    if analysis.flapping.frequency > 0.0 {
+        println!("    Aerodynamic:           {:.0} W", analysis.flapping.aerodynamic_power);
+        println!("    Inertial:              {:.0} W", analysis.flapping.inertial_power);
+    }
+    println!("  Climb Power:             {:.0} W", analysis.power_for_climb);
+    println!("  Total Power Required:    {:.0} W", analysis.total_power_required);
+    println!("  Power Loading:           {:.2} W/N", analysis.power_loading);
+    println!();
+
+    let flapping = &analysis.flapping;
+    if flapping.frequency > 0.0 {
+        println!("Flapping");
+        println!("  Reduced Frequency k:     {:.3}", flapping.reduced_frequency);
+        println!("  Strouhal Number:         {:.3}", flapping.strouhal_number);
+        println!("  Theodorsen |C(k)|:       {:.3} ({:.1}° lag)", flapping.lift_deficiency, flapping.phase_lag);
+        println!("  Mean Lift:               {:.0} N ({:.2}× fixed wing)", flapping.lift, flapping.lift_factor);
+        println!("  Mean Thrust:             {:.1} N", flapping.thrust);
+        println!("  Propulsive Efficiency:   {:.1}%", flapping.propulsive_efficiency * 100.0);
+        let peak = |value: fn(&FlappingSample) -> f32| flapping.samples.iter().map(value).fold(f32::MIN, f32::max);
+        println!("  Peak Lift / Thrust:      {:.0} N / {:.0} N", peak(|sample| sample.lift), peak(|sample| sample.thrust));
+        println!("  Peak Power:              {:.0} W", peak(|sample| sample.aerodynamic_power + sample.inertial_power));
+        let mechanism = &flapping.mechanism;
+        println!("  Wing Inertia:            {:.1} kg·m²", mechanism.wing_inertia);
+        if mechanism.resonant_frequency > 0.0 {
+            println!("  Spring Stiffness:        {:.0} N·m/rad", mechanism.spring_stiffness);
+            println!("  Resonant Frequency:      {:.2} Hz ({:+.1}% off)", mechanism.resonant_frequency, (mechanism.frequency_ratio - 1.0) * 100.0);
+            println!("  Spring Losses:           {:.0} W", mechanism.damping_power);
+        }
+        println!("  Inertial Power:          {:.0} W ({:.0} W unsprung)", flapping.inertial_power, mechanism.unsprung_power);
+        println!();
+    }
+
+    let propeller = &analysis.propeller;
+    println!("Propeller");
+    println!("  Pitch:                   {:.2} m", params.propeller_pitch);
+    println!("  Shaft Speed:             {:.0} rpm (full {:.0} rpm)", propeller.rpm, params.propeller_rpm);
+    println!("  Efficiency:              {:.3}", propeller.efficiency);
+    println!("  Advance Ratio J:         {:.3}", propeller.advance_ratio);
+    println!("  Thrust:                  {:.1} N", propeller.thrust);
+    println!("  Induced Velocity:        {:.2} m/s", propeller.induced_velocity);
+    println!("  Blade Angle of Attack:   {:.1}°", propeller.blade_angle_of_attack.to_degrees());
+    println!("  Solidity:                {:.3}", Propeller::from_params(params).solidity());
+    println!("  Blades Stalled:          {}", yes_no(propeller.stalled));
+    println!("  Short at Full RPM:       {}", yes_no(propeller.limited));
+    println!();
+
+    println!("Capabilities");
+    println!("  Can Take Off:            {}", yes_no(analysis.can_takeoff));
+    println!("  Sustained Level Flight:  {}", yes_no(analysis.can_sustain_level_flight));
+    println!("  Climb Rate:              {:.2} m/s", analysis.can_climb);
+    let motor_flight_time = if analysis.battery.capacity_ah <= 0.0 {
+        "no battery".to_string()
+    } else if analysis.motor_flight_time.is_finite() {
+        format!("{:.1} min", analysis.motor_flight_time)
+    } else {
+        "motor idle at cruise".to_string()
+    };
+    println!("  Motor Flight Time:       {}", motor_flight_time);
+    println!("  Takeoff Distance:        {:.1} m", analysis.takeoff_distance);
+    println!("  Flutter Margin:          {:.2}", analysis.flutter_margin);
+    println!();
+
+    let pilot = &analysis.pilot;
+    let duration = |minutes: f32| if minutes.is_finite() { format!("{:.1} min", minutes) } else { "unlimited".to_string() };
+    println!("Pilot");
+    println!("  Critical Power:          {:.0} W", pilot.critical_power);
+    println!("  Anaerobic Capacity W':   {:.1} kJ", pilot.anaerobic_capacity / 1000.0);
+    println!("  One-Minute Power:        {:.0} W", pilot.burst_power);
+    println!("  Cruise Pedal Power:      {:.0} W", pilot.power_required);
+    println!("  Pilot Endurance:         {}", duration(pilot.endurance));
+    println!("  Max Flight Duration:     {}", duration(pilot.max_flight_duration));
+    println!();
+
+    let battery = &analysis.battery;
+    println!("Battery");
+    if battery.capacity_ah > 0.0 {
+        println!("  Pack Mass:               {:.2} kg", battery.mass);
+        println!("  Nominal Voltage:         {:.1} V", battery.nominal_voltage);
+        println!("  Capacity:                {:.1} Ah", battery.capacity_ah);
+        println!("  Usable Energy:           {:.0} Wh", battery.usable_energy);
+        println!("  Internal Resistance:     {:.3} Ω", battery.internal_resistance);
+        println!("  Cruise Current:          {:.1} A ({:.2}C)", battery.load.current, battery.load.c_rate);
+        println!("  Voltage Sag:             {:.2} V", battery.load.voltage_sag);
+        println!("  Endurance:               {}", motor_flight_time);
+    } else {
+        println!("  Pack:                    no battery");
+    }
+    println!();
+
+    let takeoff = &analysis.takeoff;
+    println!("Takeoff");
+    println!("  Liftoff Speed:           {:.2} m/s", takeoff.liftoff_speed);
+    println!("  Ground Roll:             {:.1} m", takeoff.ground_roll);
+    println!("  Time to Liftoff:         {:.1} s", takeoff.time_to_liftoff);
+    println!("  Climb Angle:             {:.1}°", takeoff.climb_angle.to_degrees());
+    println!("  Air Distance (15 m):     {:.1} m", takeoff.air_distance);
+    println!("  Distance over 15 m:      {:.1} m", takeoff.obstacle_distance);
+    println!();
+
+    let landing = &analysis.landing;
+    println!("Landing");
+    println!("  Approach Speed:          {:.2} m/s", landing.approach_speed);
+    println!("  Glide Angle:             {:.1}°", landing.approach_angle.to_degrees());
+    println!("  Approach Sink Rate:      {:.2} m/s", landing.approach_sink_rate);
+    println!("  Flare Height:            {:.2} m", landing.flare_height);
+    println!("  Touchdown Speed:         {:.2} m/s", landing.touchdown_speed);
+    println!("  Air Distance (15 m):     {:.1} m", landing.air_distance);
+    println!("  Ground Roll:             {:.1} m", landing.ground_roll);
+    println!("  Landing Distance:        {:.1} m", landing.landing_distance);
+    println!();
+
+    println!("Structure");
+    println!("  Structurally Feasible:   {}", yes_no(structural.structural_feasible));
+    println!("  Wing Mass (each):        {:.2} kg", structural.wing_mass);
+    println!("  Spar Mass (each):        {:.2} kg", structural.spar_mass);
+    let spar = &structural.spar;
+    if spar.laminate.is_none() {
+        println!("  Spar Material:           {}", params.spar_material.name);
+    }
+    println!("  Spar Section:            {:?}, {:.0} mm deep", spar.section, spar.depth * 1000.0);
+    if spar.wall_thickness > 0.0 {
+        println!("  Spar Wall:               {:.2} mm", spar.wall_thickness * 1000.0);
+    }
+    println!("  Spar Modulus:            {:.1} GPa ({:.0} kg/m³)", spar.modulus / 1e9, spar.density);
+    println!("  Spar Bending Stiffness:  {:.0} N·m²", spar.modulus * spar.second_moment);
+    if let Some(laminate) = &spar.laminate {
+        println!("  Laminate:                {} plies, Ex {:.1} GPa, Gxy {:.1} GPa, νxy {:.2}",
+            laminate.plies.len(), laminate.axial_modulus / 1e9, laminate.shear_modulus / 1e9, laminate.poisson_ratio);
+    }
+    println!("  Battery Mass:            {:.2} kg", structural.battery_mass);
+    println!("  Total Structural Mass:   {:.2} kg", structural.total_structural_mass);
+    println!("  Max Load Factor:         {:.2} g", structural.max_load_factor);
+    println!("  Wing Deflection:         {:.3} m", structural.wing_deflection);
+    println!("  Critical Flutter Speed:  {:.1} m/s", structural.critical_flutter_speed);
+    println!("  Root Bending Stress:     {:.1} MPa (allowable {:.0} MPa)", structural.root_bending_stress / 1e6, spar.bending_strength / 1e6);
+    println!("  Root Shear Stress:       {:.2} MPa (allowable {:.0} MPa)", structural.root_shear_stress / 1e6, spar.shear_strength / 1e6);
+    println!("  Strength Margin:         {:.2}", structural.strength_margin);
+    println!("  Fatigue Margin:          {:.2} (1 g stress {:.1} MPa)", structural.fatigue_margin, structural.cruise_bending_stress / 1e6);
+    println!("  Material Cost:           ${:.0} ({} skin, ${:.0}/kg spar)", structural.material_cost, params.wing_material.name, spar.cost_per_kg);
+}
+
+fn print_sweep_table(sweep: &SpeedSweep) {
+    let speed_text = |speed: Option<f32>| speed.map_or("-".to_string(), |v| format!("{:.2} m/s", v));
+
+    println!("Stall Speed:             {:.2} m/s", sweep.stall_speed);
+    println!("V_min_power:             {} ({:.0} W)", speed_text(sweep.v_min_power), sweep.min_power_required);
+    println!("V_max_L/D:               {} (L/D {:.2})", speed_text(sweep.v_max_lift_to_drag), sweep.max_lift_to_drag);
+    let v_max_text = match sweep.points.last().filter(|_| sweep.v_max_above_sweep) {
+        Some(top) => format!("> {:.2} m/s (sweep limit)", top.airspeed),
+        None => speed_text(sweep.v_max),
+    };
+    println!("V_max (sustained):       {}", v_max_text);
+    println!("Pilot Sustained Power:   {:.0} W", sweep.pilot_sustained_power);
+    println!("Pilot Burst Power:       {:.0} W", sweep.pilot_burst_power);
+    println!("Motor Shaft Power:       {:.0} W", sweep.motor_shaft_power);
+    println!();
+    println!("{:>8} {:>7} {:>8} {:>8} {:>9} {:>8} {:>7} {:>10}", "V (m/s)", "α (°)", "CL", "CD", "Drag (N)", "L/D", "η_prop", "P_req (W)");
+    for point in &sweep.points {
+        println!(
+            "{:>8.2} {:>7.2} {:>8.3} {:>8.4} {:>9.1} {:>8.2} {:>7.3} {:>10.0}{}",
+            point.airspeed,
+            point.angle_of_attack,
+            point.lift_coefficient,
+            point.drag_coefficient,
+            point.drag_force,
+            point.lift_to_drag,
+            point.propeller_efficiency,
+            point.power_required,
+            if point.stalled { "  stalled" } else if point.propeller_limited { "  propeller at full RPM" } else { "" }
+        );
+    }
+}
+
+fn print_mission_summary(result: &MissionResult) {
+    let optional = |value: Option<f32>, unit: &str| value.map_or("-".to_string(), |v| format!("{:.1} {}", v, unit));
+
+    println!("Mission Completed:       {}", yes_no(result.completed));
+    if let Some(reason) = &result.abort_reason {
+        println!("Reason:                  {}", reason);
+    }
+    println!("Liftoff Time:            {}", optional(result.liftoff_time, "s"));
+    println!("Ground Roll:             {}", optional(result.ground_roll_distance, "m"));
+    println!("Max Altitude:            {:.1} m", result.max_altitude);
+    println!("Total Distance:          {:.0} m", result.total_distance);
+    println!("Flight Time:             {:.1} s", result.flight_time);
+    println!("Mission Time:            {:.1} s", result.mission_time);
+    println!("Battery Used:            {:.1} Wh", result.battery_used);
+    println!("Peak Pilot Fatigue:      {:.0}%", result.peak_pilot_fatigue * 100.0);
+    println!("Touchdown Airspeed:      {}", optional(result.touchdown_airspeed, "m/s"));
+    println!("Touchdown Sink Rate:     {}", optional(result.touchdown_sink_rate, "m/s"));
+    println!("Rollout Distance:        {}", optional(result.rollout_distance, "m"));
+    println!();
+    println!("{:>7} {:<10} {:>8} {:>7} {:>7} {:>7} {:>8} {:>8}", "t (s)", "Phase", "x (m)", "h (m)", "V (m/s)", "γ (°)", "SoC", "Fatigue");
+
+    // Phase changes plus every tenth sample keeps the timeline readable
+    let mut last_phase = None;
+    for (index, sample) in result.samples.iter().enumerate() {
+        if last_phase != Some(sample.phase) || index % 10 == 0 || index + 1 == result.samples.len() {
+            println!(
+                "{:>7.1} {:<10} {:>8.0} {:>7.2} {:>7.2} {:>7.2} {:>7.0}% {:>7.0}%",
+                sample.time,
+                format!("{:?}", sample.phase),
+                sample.distance,
+                sample.altitude,
+                sample.airspeed,
+                sample.flight_path_angle.to_degrees(),
+                sample.battery_state_of_charge * 100.0,
+                sample.pilot_fatigue * 100.0
+            );
+            last_phase = Some(sample.phase);
+        }
+    }
+}
+
+fn print_json<T: serde::Serialize>(value: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).map_err(|err| format!("failed to serialize output: {}", err))?;
+    println!("{}", json);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut params = match load_source(&args.source) {
+        Ok(params) => params,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return ExitCode::from(2);
+        }
+    };
+    if let Some(coordinates) = &args.airfoil {
+        match import_airfoil(coordinates, &args.polars) {
+            Ok(airfoil) => params.use_imported_airfoil(airfoil),
+            Err(err) => {
+                eprintln!("error: failed to import airfoil: {}", err);
+                return ExitCode::from(2);
+            }
+        }
+    }
+    if let Some(designation) = &args.naca {
+        if let Err(message) = params.use_naca_airfoil(designation) {
+            eprintln!("error: {}", message);
+            return ExitCode::from(2);
+        }
+    }
+
+    let analysis = calculate_comprehensive_flight_analysis(&params);
+
+    let printed = match (args.command, args.format) {
+        (Command::Analyze, OutputFormat::Text) => {
+            print_text_report(&params, &analysis);
+            Ok(())
+        }
+        (Command::Analyze, OutputFormat::Json) => print_json(&analysis),
+        (Command::Sweep, OutputFormat::Text) => {
+            print_sweep_table(&calculate_speed_sweep(&params));
+            Ok(())
+        }
+        (Command::Sweep, OutputFormat::Json) => print_json(&calculate_speed_sweep(&params)),
+        (Command::Simulate, OutputFormat::Text) => {
+            print_mission_summary(&simulate_mission(&params, &MissionProfile::default()));
+            Ok(())
+        }
+        (Command::Simulate, OutputFormat::Json) => print_json(&simulate_mission(&params, &MissionProfile::default())),
+    };
+    if let Err(message) = printed {
+        eprintln!("error: {}", message);
+        return ExitCode::from(2);
+    }
+
+    if analysis.can_sustain_level_flight {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::materials::MaterialLibrary;
+use crate::params::FlightParams;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    // Anything that isn't explicitly .json is treated as TOML
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Toml,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    TomlSerialize(toml::ser::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "I/O error: {}", err),
+            ConfigError::Toml(err) => write!(f, "invalid TOML: {}", err),
+            ConfigError::TomlSerialize(err) => write!(f, "TOML serialization failed: {}", err),
+            ConfigError::Json(err) => write!(f, "invalid JSON: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(err: std::io::Error) -> Self {
+        ConfigError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(err: toml::de::Error) -> Self {
+        ConfigError::Toml(err)
+    }
+}
+
+impl From<toml::ser::Error> for ConfigError {
+    fn from(err: toml::ser::Error) -> Self {
+        ConfigError::TomlSerialize(err)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(err: serde_json::Error) -> Self {
+        ConfigError::Json(err)
+    }
+}
+
+// Fields older designs may still carry: the air density now follows from the altitude and
+// temperature, and the Oswald factor from the span loading
+const RETIRED_KEYS: [&str; 2] = ["air_density", "oswald_efficiency"];
+
+// Drop retired keys before the strict deserializer sees them
+pub(crate) fn migrate_params(params: &mut toml::Table) {
+    for key in RETIRED_KEYS {
+        params.remove(key);
+    }
+}
+
+fn migrate_json_params(params: &mut serde_json::Value) {
+    if let Some(params) = params.as_object_mut() {
+        for key in RETIRED_KEYS {
+            params.remove(key);
+        }
+    }
+}
+
+pub fn parse_params(text: &str, format: ConfigFormat, materials: &MaterialLibrary) -> Result<FlightParams, ConfigError> {
+    // Missing fields fall back to FlightParams::default(); material names are looked up in the
+    // library the caller loaded, so parsing never reads the user directory
+    let params = match format {
+        ConfigFormat::Toml => {
+            let mut table: toml::Table = toml::from_str(text)?;
+            migrate_params(&mut table);
+            materials.expand_names(&mut table)?;
+            toml::Value::Table(table).try_into()?
+        }
+        ConfigFormat::Json => {
+            let mut value: serde_json::Value = serde_json::from_str(text)?;
+            migrate_json_params(&mut value);
+            materials.expand_json_names(&mut value)?;
+            serde_json::from_value(value)?
+        }
+    };
+    Ok(params)
+}
+
+pub fn load_params(path: &Path, materials: &MaterialLibrary) -> Result<FlightParams, ConfigError> {
+    let text = fs::read_to_string(path)?;
+    parse_params(&text, ConfigFormat::from_path(path), materials)
+}
+
+pub fn serialize_params(params: &FlightParams, format: ConfigFormat) -> Result<String, ConfigError> {
+    let text = match format {
+        ConfigFormat::Toml => toml::to_string_pretty(params)?,
+        ConfigFormat::Json => serde_json::to_string_pretty(params)?,
+    };
+    Ok(text)
+}
+
+pub fn save_params(path: &Path, params: &FlightParams) -> Result<(), ConfigError> {
+    let text = serialize_params(params, ConfigFormat::from_path(path))?;
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, text)?;
+    Ok(())
+}
+
+// Per-user settings directory: $XDG_CONFIG_HOME/ascent, ~/.config/ascent or %APPDATA%\ascent
+pub fn user_config_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("ascent"))
+}
+
+// "My Tandem v2" -> "my_tandem_v2", for files named after presets and materials
+pub(crate) fn file_stem(name: &str, fallback: &str) -> String {
+    let stem: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    let stem = stem.trim_matches('_').to_string();
+    if stem.is_empty() { fallback.to_string() } else { stem }
+}
+
+const MAX_RECENT_FILES: usize = 8;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RecentFiles {
+    pub paths: Vec<PathBuf>,
+}
+
+impl RecentFiles {
+    fn storage_path() -> Option<PathBuf> {
+        user_config_dir().map(|dir| dir.join("recent_files.toml"))
+    }
+
+    // A missing or unreadable list simply starts empty
+    pub fn load() -> Self {
+        Self::storage_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| toml::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let Some(path) = Self::storage_path() else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    // Most recent first, without duplicates
+    pub fn push(&mut self, path: &Path) {
+        self.paths.retain(|existing| existing != path);
+        self.paths.insert(0, path.to_path_buf());
+        self.paths.truncate(MAX_RECENT_FILES);
+    }
+}
@@ -0,0 +1,260 @@
+use std::f32::consts::PI;
+
+use serde::Serialize;
+
+use crate::airfoil::SectionPolar;
+use crate::lift_curve::LiftCurve;
+use crate::multiplane::surface_placements;
+use crate::params::FlightParams;
+
+const STRIPS: usize = 12;  // Spanwise strips per wing
+const STROKE_STEPS: usize = 48;  // Phases per stroke cycle
+// The cambered section stalls early at negative incidence on the upstroke
+const NEGATIVE_CL_MAX_FRACTION: f32 = 0.5;
+
+// Forces and power at one phase of the stroke, all wings together
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct FlappingSample {
+    pub phase: f32,  // Fraction of the cycle from the top of the front wings' stroke
+    pub flap_angle: f32,  // deg above the mean position, front wings
+    pub lift: f32,  // N
+    pub thrust: f32,  // N, beyond the fixed-wing profile drag
+    pub aerodynamic_power: f32,  // W done on the air
+    pub inertial_power: f32,  // W the mechanism puts into the wings, springs and their losses
+}
+
+// Wings swinging on torsion springs about their root hinges: the springs take up the kinetic
+// energy of each half stroke and hand it back, completely at resonance
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FlappingMechanism {
+    pub wing_inertia: f32,  // kg·m² of a main wing and its added mass about the hinge
+    pub spring_stiffness: f32,  // N·m/rad per main wing
+    pub damping_coefficient: f32,  // N·m·s/rad per main wing
+    pub resonant_frequency: f32,  // Hz, zero without a spring
+    pub frequency_ratio: f32,  // Flapping frequency over the resonant frequency
+    pub unsprung_power: f32,  // W the wings' inertia would cost with no springs
+    pub damping_power: f32,  // W lost in the springs
+}
+
+// Quasi-steady strip model of wings rotating about their roots: each strip sees the stroke velocity
+// through Theodorsen's lift deficiency, feathers with the stroke, stalls on the lift curve and
+// carries its added mass
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FlappingCycle {
+    pub frequency: f32,  // Hz
+    pub reduced_frequency: f32,  // k = πfc/V on the mean aerodynamic chord
+    pub strouhal_number: f32,  // St = fA/V, A the peak-to-peak tip excursion
+    pub lift_deficiency: f32,  // |C(k)|
+    pub phase_lag: f32,  // deg, circulatory lift behind the plunge velocity
+    pub lift: f32,  // N, stroke-averaged
+    pub lift_factor: f32,  // Mean lift over the fixed-wing lift at the same angle of attack
+    pub thrust: f32,  // N, stroke-averaged, beyond the fixed-wing profile drag
+    pub aerodynamic_power: f32,  // W, stroke-averaged
+    pub inertial_power: f32,  // W, wing inertia net of the springs, plus the spring losses
+    pub propulsive_efficiency: f32,  // TV over the aerodynamic power
+    pub mechanism: FlappingMechanism,
+    pub samples: Vec<FlappingSample>,
+}
+
+// One spanwise strip of a wing, with what stays fixed over the stroke
+struct Strip {
+    radius: f32,  // m from the root hinge
+    chord: f32,
+    width: f32,
+    alpha: f32,  // deg, geometric at mid-stroke
+    feathering_share: f32,  // Fraction of the tip feathering reached here
+    theodorsen: (f32, f32),
+    added_mass: f32,  // kg
+}
+
+// Theodorsen's function C(k) = F + iG, R. T. Jones's two-pole approximation
+pub fn theodorsen_function(reduced_frequency: f32) -> (f32, f32) {
+    let k = reduced_frequency.max(1e-6);
+    [(0.165, 0.0455), (0.335, 0.3)].iter().fold((1.0, 0.0), |(f, g), &(a, b)| {
+        let denominator = k * k + b * b;
+        (f - a * k * k / denominator, g - a * b * k / denominator)
+    })
+}
+
+// Stroke phase ψ at a fraction of the cycle, with its rate as a multiple of ω; the downstroke
+// takes ψ from 0 to π in its share of the period, the upstroke from π back to 2π
+pub fn stroke_phase(fraction: f32, upstroke_ratio: f32) -> (f32, f32) {
+    let downstroke = 1.0 / (1.0 + upstroke_ratio.max(0.1));
+    let fraction = fraction.rem_euclid(1.0);
+    if fraction < downstroke {
+        (PI * fraction / downstroke, 0.5 / downstroke)
+    } else {
+        (PI + PI * (fraction - downstroke) / (1.0 - downstroke), 0.5 / (1.0 - downstroke))
+    }
+}
+
+impl FlappingCycle {
+    pub fn total_power(&self) -> f32 {
+        self.aerodynamic_power + self.inertial_power
+    }
+
+    // Stroke-averaged forces at a root angle of attack; a fixed wing gives zero thrust and power
+    pub fn at_airspeed(
+        params: &FlightParams,
+        lift_curve: &LiftCurve,
+        section: &SectionPolar,
+        wing_mass: f32,
+        air_density: f32,
+        airspeed: f32,
+        angle_of_attack: f32,
+    ) -> Self {
+        if params.flapping_frequency <= 0.1 {
+            return Self { lift_factor: 1.0, ..Self::default() };
+        }
+        let airspeed = airspeed.max(0.1);
+        let planform = params.planform();
+        let amplitude = params.flapping_amplitude.to_radians();
+        let omega = 2.0 * PI * params.flapping_frequency;
+        let stroke_plane = params.flapping_stroke_plane.to_radians();
+        let (stroke_forward, stroke_down) = stroke_plane.sin_cos();
+        let section_cl = |alpha: f32| lift_curve.lift_coefficient(alpha).max(-NEGATIVE_CL_MAX_FRACTION * lift_curve.cl_max);
+
+        // Each pair of wings, with its strips and inertia about the root hinge
+        let surfaces: Vec<(f32, Vec<Strip>, f32)> = surface_placements(params)
+            .iter()
+            .enumerate()
+            .map(|(index, placement)| {
+                let wing = planform.scaled(placement.scale);
+                let span = wing.projected_span();
+                let width = span / STRIPS as f32;
+                let strips: Vec<Strip> = (0..STRIPS)
+                    .map(|strip| {
+                        let station = wing.station((strip as f32 + 0.5) * width);
+                        Strip {
+                            radius: station.y,
+                            chord: station.chord,
+                            width,
+                            alpha: angle_of_attack + placement.incidence - station.washout,
+                            feathering_share: station.y / span.max(1e-6),
+                            theodorsen: theodorsen_function(PI * params.flapping_frequency * station.chord / airspeed),
+                            added_mass: air_density * PI * station.chord.powi(2) / 4.0 * width,
+                        }
+                    })
+                    .collect();
+                // Structure scales with area, so a wing's inertia goes as the fourth power of its size
+                let inertia = wing_mass * placement.scale.powi(2) * span.powi(2) / 3.0
+                    + strips.iter().map(|strip| strip.added_mass * strip.radius.powi(2)).sum::<f32>();
+                let phase_offset = index as f32 * params.flapping_phase_offset / 360.0;
+                (phase_offset, strips, inertia)
+            })
+            .collect();
+
+        // Springs are sized with each wing's inertia, so every pair shares the main wing's resonance
+        let main_inertia = surfaces
+            .iter()
+            .zip(surface_placements(params))
+            .find(|(_, placement)| placement.scale == 1.0)
+            .map_or(1.0, |((_, _, inertia), _)| *inertia)
+            .max(1e-6);
+        let stiffness = params.flapping_spring_stiffness.max(0.0);
+        let damping = 2.0 * params.flapping_damping_ratio.max(0.0) * (stiffness * main_inertia).sqrt();
+        let mut unsprung_power = 0.0;
+        let mut damping_power = 0.0;
+
+        let q = 0.5 * air_density * airspeed.powi(2);
+        let (mut steady_lift, mut steady_drag) = (0.0, 0.0);
+        for strip in surfaces.iter().flat_map(|(_, strips, _)| strips) {
+            let cl = section_cl(strip.alpha);
+            steady_lift += 2.0 * q * strip.chord * strip.width * cl;
+            steady_drag += 2.0 * q * strip.chord * strip.width * section.profile_drag(cl);
+        }
+
+        // Flap angle θ = Φ cos ψ; the stroke velocity is tilted forward by the stroke plane angle
+        let samples: Vec<FlappingSample> = (0..STROKE_STEPS)
+            .map(|step| {
+                let fraction = step as f32 / STROKE_STEPS as f32;
+                let mut sample = FlappingSample {
+                    phase: fraction,
+                    flap_angle: (amplitude * stroke_phase(fraction, params.flapping_upstroke_ratio).0.cos()).to_degrees(),
+                    lift: 0.0,
+                    thrust: steady_drag,
+                    aerodynamic_power: 0.0,
+                    inertial_power: 0.0,
+                };
+                for (phase_offset, strips, inertia) in &surfaces {
+                    let (psi, rate) = stroke_phase(fraction - phase_offset, params.flapping_upstroke_ratio);
+                    let flap_angle = amplitude * psi.cos();
+                    let stroke_rate = amplitude * omega * rate * psi.sin();  // rad/s downwards
+                    let stroke_acceleration = amplitude * (omega * rate).powi(2) * psi.cos();
+                    let feathering = -params.flapping_pitch_amplitude * (psi - params.flapping_pitch_lag.to_radians()).sin();
+                    let (mut upward, mut thrust, mut power) = (0.0, 0.0, 0.0);
+                    for strip in strips {
+                        let (f, g) = strip.theodorsen;
+                        let plunge = strip.radius * stroke_rate;
+                        let lagged_plunge = strip.radius * amplitude * omega * rate * (f * psi.sin() + g * psi.cos());
+                        let forward_speed = airspeed + plunge * stroke_forward;
+                        let inflow = (plunge * stroke_down).atan2(forward_speed);
+                        let alpha = strip.alpha + strip.feathering_share * feathering
+                            + (lagged_plunge * stroke_down).atan2(forward_speed).to_degrees();
+                        let cl = section_cl(alpha);
+                        let local_q = 0.5 * air_density * (forward_speed.powi(2) + (plunge * stroke_down).powi(2));
+                        let lift = local_q * strip.chord * strip.width * cl;
+                        let drag = local_q * strip.chord * strip.width * section.profile_drag(cl);
+                        // Added mass resists the stroke acceleration
+                        let added_mass_force = strip.added_mass * strip.radius * stroke_acceleration * stroke_down;
+                        let strip_upward = lift * inflow.cos() + drag * inflow.sin() + added_mass_force;
+                        let strip_forward = lift * inflow.sin() - drag * inflow.cos();
+                        upward += strip_upward;
+                        // Garrick: only (F² + G²)/F of the tilted lift survives the energy shed into the wake
+                        thrust += lift * inflow.sin() * (f * f + g * g) / f - drag * inflow.cos();
+                        power += plunge * (strip_upward * stroke_down - strip_forward * stroke_forward);
+                    }
+                    // Mechanism moment I θ̈ + c θ̇ + k θ; the pilot cannot take back the energy of a
+                    // decelerating wing, so only what the spring does not absorb is lost
+                    let share = inertia / main_inertia;
+                    let flap_rate = -amplitude * omega * rate * psi.sin();
+                    let flap_acceleration = -stroke_acceleration;
+                    let reactive_power = (inertia * flap_acceleration + share * stiffness * flap_angle) * flap_rate;
+                    let spring_loss = share * damping * flap_rate.powi(2);
+                    unsprung_power += 2.0 * (inertia * flap_acceleration * flap_rate).max(0.0);
+                    damping_power += 2.0 * spring_loss;
+                    sample.lift += 2.0 * upward * flap_angle.cos();
+                    sample.thrust += 2.0 * thrust;
+                    sample.aerodynamic_power += 2.0 * power;
+                    sample.inertial_power += 2.0 * (reactive_power.max(0.0) + spring_loss);
+                }
+                sample
+            })
+            .collect();
+
+        let mean = |value: fn(&FlappingSample) -> f32| samples.iter().map(value).sum::<f32>() / STROKE_STEPS as f32;
+        let lift = mean(|sample| sample.lift);
+        let thrust = mean(|sample| sample.thrust);
+        let aerodynamic_power = mean(|sample| sample.aerodynamic_power);
+        let inertial_power = mean(|sample| sample.inertial_power);
+
+        let span = planform.projected_span();
+        let mean_chord = planform.mean_aerodynamic_chord();
+        let reduced_frequency = PI * params.flapping_frequency * mean_chord / airspeed;
+        let (f, g) = theodorsen_function(reduced_frequency);
+        let resonant_frequency = (stiffness / main_inertia).sqrt() / (2.0 * PI);
+        Self {
+            frequency: params.flapping_frequency,
+            reduced_frequency,
+            strouhal_number: params.flapping_frequency * 2.0 * span * amplitude.sin() / airspeed,
+            lift_deficiency: f.hypot(g),
+            phase_lag: (-g).atan2(f).to_degrees(),
+            lift,
+            lift_factor: if steady_lift.abs() > 1e-6 { lift / steady_lift } else { 1.0 },
+            thrust,
+            aerodynamic_power,
+            inertial_power,
+            propulsive_efficiency: if aerodynamic_power > 0.0 { (thrust * airspeed / aerodynamic_power).max(0.0) } else { 0.0 },
+            mechanism: FlappingMechanism {
+                wing_inertia: main_inertia,
+                spring_stiffness: stiffness,
+                damping_coefficient: damping,
+                resonant_frequency,
+                frequency_ratio: if resonant_frequency > 0.0 { params.flapping_frequency / resonant_frequency } else { 0.0 },
+                unsprung_power: unsprung_power / STROKE_STEPS as f32,
+                damping_power: damping_power / STROKE_STEPS as f32,
+            },
+            samples,
+        }
+    }
+}
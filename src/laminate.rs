@@ -0,0 +1,242 @@
+use serde::{Deserialize, Serialize};
+
+use crate::numeric::solve_linear;
+
+// Epoxy prepregs at about 60% fibre volume
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PlyMaterial {
+    CarbonUd,  // High-strength unidirectional carbon
+    CarbonFabric,  // Plain-weave carbon
+    GlassFabric,  // E-glass weave
+    AramidUd,  // Unidirectional Kevlar 49
+}
+
+// Engineering constants of one ply in its fibre axes
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct LaminaProperties {
+    pub e1: f32,  // Pa along the fibres
+    pub e2: f32,  // Pa across the fibres
+    pub g12: f32,  // Pa in-plane shear
+    pub nu12: f32,
+    pub density: f32,  // kg/m³
+    pub tensile_strength: f32,  // Pa along the fibres
+    pub compressive_strength: f32,  // Pa along the fibres
+    pub transverse_tensile_strength: f32,  // Pa
+    pub transverse_compressive_strength: f32,  // Pa
+    pub shear_strength: f32,  // Pa in-plane
+    pub fatigue_ratio: f32,  // Fatigue limit over static tensile strength
+    pub cost_per_kg: f32,  // USD, prepreg
+}
+
+// One layer of a laminate, listed from the inner surface outwards
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ply {
+    pub material: PlyMaterial,
+    pub thickness: f32,  // mm
+    pub angle: f32,  // deg from the spar axis
+}
+
+// Effective properties of a laminate from classical lamination theory
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Laminate {
+    pub plies: Vec<Ply>,
+    pub thickness: f32,  // m
+    pub density: f32,  // kg/m³
+    pub axial_modulus: f32,  // Pa, Ex from the membrane compliance
+    pub transverse_modulus: f32,  // Pa, Ey
+    pub shear_modulus: f32,  // Pa, Gxy
+    pub poisson_ratio: f32,  // νxy
+    pub bending_modulus: f32,  // Pa, Ex from the bending compliance
+    pub axial_stiffness: f32,  // N/m, A11
+    pub bending_stiffness: f32,  // N·m, D11
+    pub tensile_strength: f32,  // Pa of mean axial stress at first ply failure
+    pub compressive_strength: f32,  // Pa
+    pub shear_strength: f32,  // Pa
+    pub fatigue_limit: f32,  // Pa
+    pub cost_per_kg: f32,  // USD
+}
+
+impl PlyMaterial {
+    pub fn properties(&self) -> LaminaProperties {
+        match self {
+            PlyMaterial::CarbonUd => LaminaProperties {
+                e1: 135e9, e2: 10e9, g12: 5.0e9, nu12: 0.30, density: 1550.0,
+                tensile_strength: 1500e6, compressive_strength: 1200e6,
+                transverse_tensile_strength: 40e6, transverse_compressive_strength: 200e6, shear_strength: 70e6,
+                fatigue_ratio: 0.6, cost_per_kg: 40.0,
+            },
+            PlyMaterial::CarbonFabric => LaminaProperties {
+                e1: 70e9, e2: 70e9, g12: 5.0e9, nu12: 0.10, density: 1500.0,
+                tensile_strength: 600e6, compressive_strength: 570e6,
+                transverse_tensile_strength: 600e6, transverse_compressive_strength: 570e6, shear_strength: 90e6,
+                fatigue_ratio: 0.5, cost_per_kg: 50.0,
+            },
+            PlyMaterial::GlassFabric => LaminaProperties {
+                e1: 25e9, e2: 25e9, g12: 4.0e9, nu12: 0.20, density: 1900.0,
+                tensile_strength: 440e6, compressive_strength: 425e6,
+                transverse_tensile_strength: 440e6, transverse_compressive_strength: 425e6, shear_strength: 50e6,
+                fatigue_ratio: 0.3, cost_per_kg: 15.0,
+            },
+            PlyMaterial::AramidUd => LaminaProperties {
+                e1: 76e9, e2: 5.5e9, g12: 2.1e9, nu12: 0.34, density: 1380.0,
+                tensile_strength: 1400e6, compressive_strength: 235e6,
+                transverse_tensile_strength: 12e6, transverse_compressive_strength: 53e6, shear_strength: 34e6,
+                fatigue_ratio: 0.55, cost_per_kg: 45.0,
+            },
+        }
+    }
+}
+
+impl Ply {
+    // Transformed reduced stiffness Q̄ in the laminate axes, GPa, Voigt order (x, y, xy)
+    fn stiffness(&self) -> [[f32; 3]; 3] {
+        let lamina = self.material.properties();
+        let (e1, e2, g12) = (lamina.e1 / 1e9, lamina.e2 / 1e9, lamina.g12 / 1e9);
+        let nu21 = lamina.nu12 * e2 / e1;
+        let denominator = 1.0 - lamina.nu12 * nu21;
+        let (q11, q22, q12, q66) = (e1 / denominator, e2 / denominator, lamina.nu12 * e2 / denominator, g12);
+
+        let (n, m) = self.angle.to_radians().sin_cos();
+        let (m2, n2) = (m * m, n * n);
+        let q16 = (q11 - q12 - 2.0 * q66) * m2 * m * n + (q12 - q22 + 2.0 * q66) * m * n2 * n;
+        let q26 = (q11 - q12 - 2.0 * q66) * m * n2 * n + (q12 - q22 + 2.0 * q66) * m2 * m * n;
+        [
+            [
+                q11 * m2 * m2 + 2.0 * (q12 + 2.0 * q66) * m2 * n2 + q22 * n2 * n2,
+                (q11 + q22 - 4.0 * q66) * m2 * n2 + q12 * (m2 * m2 + n2 * n2),
+                q16,
+            ],
+            [
+                (q11 + q22 - 4.0 * q66) * m2 * n2 + q12 * (m2 * m2 + n2 * n2),
+                q11 * n2 * n2 + 2.0 * (q12 + 2.0 * q66) * m2 * n2 + q22 * m2 * m2,
+                q26,
+            ],
+            [q16, q26, (q11 + q22 - 2.0 * q12 - 2.0 * q66) * m2 * n2 + q66 * (m2 * m2 + n2 * n2)],
+        ]
+    }
+
+    // Maximum-strain failure index of laminate-axis strains (εx, εy, γxy); 1 is failure
+    fn failure_index(&self, strain: [f32; 3]) -> f32 {
+        let lamina = self.material.properties();
+        let (n, m) = self.angle.to_radians().sin_cos();
+        let [ex, ey, gxy] = strain;
+        let e1 = m * m * ex + n * n * ey + m * n * gxy;
+        let e2 = n * n * ex + m * m * ey - m * n * gxy;
+        let g12 = 2.0 * m * n * (ey - ex) + (m * m - n * n) * gxy;
+
+        let direct = |strain: f32, modulus: f32, tension: f32, compression: f32| {
+            strain * modulus / if strain >= 0.0 { tension } else { -compression }
+        };
+        direct(e1, lamina.e1, lamina.tensile_strength, lamina.compressive_strength)
+            .max(direct(e2, lamina.e2, lamina.transverse_tensile_strength, lamina.transverse_compressive_strength))
+            .max(g12.abs() * lamina.g12 / lamina.shear_strength)
+    }
+}
+
+// A, B and D summed about the mid-plane, assembled in GPa and mm, which keeps them within a
+// few decades of each other
+fn abd_matrix(plies: &[Ply], thickness: f32) -> Vec<Vec<f32>> {
+    let mut abd = vec![vec![0.0; 6]; 6];
+    let mut bottom = -0.5 * thickness;
+    for ply in plies {
+        let top = bottom + ply.thickness.max(0.0);
+        let q = ply.stiffness();
+        for i in 0..3 {
+            for j in 0..3 {
+                abd[i][j] += q[i][j] * (top - bottom);
+                abd[i][j + 3] += q[i][j] * (top.powi(2) - bottom.powi(2)) / 2.0;
+                abd[i + 3][j] += q[i][j] * (top.powi(2) - bottom.powi(2)) / 2.0;
+                abd[i + 3][j + 3] += q[i][j] * (top.powi(3) - bottom.powi(3)) / 3.0;
+            }
+        }
+        bottom = top;
+    }
+    abd
+}
+
+impl Laminate {
+    // A, B and D summed through the thickness and inverted together, so unsymmetric layups
+    // lose the stiffness their coupling costs; None for an empty layup
+    pub fn from_plies(plies: &[Ply]) -> Option<Self> {
+        let thickness: f32 = plies.iter().map(|ply| ply.thickness.max(0.0)).sum();  // mm
+        if thickness <= 0.0 {
+            return None;
+        }
+
+        let abd = abd_matrix(plies, thickness);
+        let identity: Vec<Vec<f32>> = (0..6).map(|i| (0..6).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+        let compliance = solve_linear(abd.clone(), identity);  // Symmetric, so columns serve as rows
+
+        // First ply failure under a unit mean stress, 1 GPa acting through the whole thickness
+        let first_failure = |load: [f32; 3]| {
+            let resultants: Vec<f32> = load.iter().map(|stress| stress * thickness).chain([0.0; 3]).collect();
+            let response: Vec<f32> = (0..6).map(|i| (0..6).map(|j| compliance[j][i] * resultants[j]).sum()).collect();
+            let mut z = -0.5 * thickness;
+            let mut worst: f32 = 0.0;
+            for ply in plies {
+                let middle = z + 0.5 * ply.thickness.max(0.0);
+                let strain = [0, 1, 2].map(|i| response[i] + middle * response[i + 3]);
+                worst = worst.max(ply.failure_index(strain));
+                z += ply.thickness.max(0.0);
+            }
+            1e9 / worst.max(1e-9)  // Pa
+        };
+        let tensile_strength = first_failure([1.0, 0.0, 0.0]);
+        let shear_strength = first_failure([0.0, 0.0, 1.0]).min(first_failure([0.0, 0.0, -1.0]));
+
+        let mass: f32 = plies.iter().map(|ply| ply.thickness.max(0.0) * ply.material.properties().density).sum();
+        let fatigue_ratio = plies.iter().map(|ply| ply.material.properties().fatigue_ratio).fold(1.0, f32::min);
+        let cost: f32 = plies.iter().map(|ply| {
+            let lamina = ply.material.properties();
+            ply.thickness.max(0.0) * lamina.density * lamina.cost_per_kg
+        }).sum();
+        Some(Self {
+            plies: plies.to_vec(),
+            thickness: thickness / 1000.0,
+            density: mass / thickness,
+            axial_modulus: 1e9 / (thickness * compliance[0][0]),
+            transverse_modulus: 1e9 / (thickness * compliance[1][1]),
+            shear_modulus: 1e9 / (thickness * compliance[2][2]),
+            poisson_ratio: -compliance[0][1] / compliance[0][0],
+            bending_modulus: 12e9 / (thickness.powi(3) * compliance[3][3]),
+            axial_stiffness: abd[0][0] * 1e6,  // GPa·mm to N/m
+            bending_stiffness: abd[3][3],  // GPa·mm³ to N·m
+            tensile_strength,
+            compressive_strength: first_failure([-1.0, 0.0, 0.0]),
+            shear_strength,
+            fatigue_limit: fatigue_ratio * tensile_strength,
+            cost_per_kg: cost / mass,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn carbon(angle: f32) -> Ply {
+        Ply { material: PlyMaterial::CarbonUd, thickness: 0.125, angle }
+    }
+
+    #[test]
+    fn single_ply_along_the_spar_has_the_fibre_modulus() {
+        let laminate = Laminate::from_plies(&[carbon(0.0)]).unwrap();
+        let e1 = PlyMaterial::CarbonUd.properties().e1;
+        assert!((laminate.axial_modulus - e1).abs() < 1e-3 * e1, "Ex {} against E1 {}", laminate.axial_modulus, e1);
+    }
+
+    #[test]
+    fn symmetric_cross_ply_is_balanced_and_uncoupled() {
+        let plies = [carbon(0.0), carbon(90.0), carbon(90.0), carbon(0.0)];
+        let laminate = Laminate::from_plies(&plies).unwrap();
+        assert!((laminate.axial_modulus - laminate.transverse_modulus).abs() < 1e-3 * laminate.axial_modulus,
+            "Ex {} against Ey {}", laminate.axial_modulus, laminate.transverse_modulus);
+
+        let abd = abd_matrix(&plies, 0.5);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(abd[i][j + 3].abs() < 1e-4 * abd[0][0], "B{}{} = {}", i + 1, j + 1, abd[i][j + 3]);
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Headless flight engineering engine: configuration, atmosphere, airfoil polars,
+//! structural analysis, flight physics, propulsion, batteries, pilot endurance, mission
+//! simulation, presets and the parameter optimizer. The macroquad/egui front end in
+//! `main.rs` is a thin layer on top of this crate.
+
+pub mod airfoil;
+pub mod airfoil_import;
+pub mod atmosphere;
+pub mod battery;
+pub mod config;
+pub mod flapping;
+pub mod laminate;
+pub mod lift_curve;
+pub mod materials;
+pub mod multiplane;
+pub mod naca;
+mod numeric;
+pub mod optimize;
+pub mod params;
+pub mod parasite;
+pub mod performance;
+pub mod physics;
+pub mod pilot;
+pub mod planform;
+pub mod presets;
+pub mod propeller;
+pub mod simulation;
+pub mod structure;
+pub mod vortex_lattice;
+
+pub use airfoil::{AirfoilData, Polar, PolarPoint, SectionPolar};
+pub use atmosphere::Atmosphere;
+pub use battery::{Battery, BatteryAnalysis, BatteryLoad};
+pub use flapping::{FlappingCycle, FlappingMechanism, FlappingSample};
+pub use laminate::{Laminate, Ply, PlyMaterial};
+pub use lift_curve::LiftCurve;
+pub use materials::{Material, MaterialLibrary, MaterialSource};
+pub use multiplane::{MunkInterference, SurfacePlacement};
+pub use naca::{NacaAirfoil, ThinAirfoilEstimate};
+pub use optimize::{optimize_parameters, score_configuration};
+pub use parasite::{DragComponent, ParasiteDrag};
+pub use performance::{calculate_speed_sweep, SpeedSweep};
+pub use params::{
+    AirfoilSection, BatteryChemistry, FairingType, FlightParams, LandingGear, PilotPosition, PilotProfile, RunwaySurface,
+    SparSection, WingLayout,
+};
+pub use physics::{
+    calculate_comprehensive_flight_analysis, FlightAnalysis, FlightPhase, LandingAnalysis, TakeoffAnalysis,
+};
+pub use pilot::{PilotAnalysis, PowerDurationCurve};
+pub use planform::{PlanformStation, WingPanel, WingPlanform};
+pub use presets::{Preset, PresetLibrary};
+pub use propeller::{Propeller, PropellerPoint};
+pub use simulation::{simulate_mission, MissionPhase, MissionProfile, MissionResult, MissionSimulator};
+pub use structure::{calculate_structural_properties, SparProperties, StructuralAnalysis};
+pub use vortex_lattice::{LiftingSurface, Planform, SpanLoading, SpanStation};
@@ -0,0 +1,141 @@
+use std::f32::consts::PI;
+
+use serde::Serialize;
+
+use crate::airfoil::SectionPolar;
+use crate::params::FlightParams;
+use crate::vortex_lattice::SpanLoading;
+
+// Without tabulated data the curve leaves the straight line at 80% of CLmax
+const DEFAULT_LINEAR_FRACTION: f32 = 0.8;
+const DEFAULT_POST_STALL_SLOPE: f32 = 0.05;  // CL lost per degree beyond the stall
+// Fully separated flow still carries roughly flat-plate lift
+const POST_STALL_FLOOR: f32 = 0.6;  // Fraction of CLmax
+
+// Wing lift coefficient against angle of attack: straight attached-flow line, a parabolic
+// rounding into CLmax at the stall angle, then a linear loss of lift down to the separated floor
+#[derive(Clone, Debug, Serialize)]
+pub struct LiftCurve {
+    pub zero_lift_angle: f32,  // deg, wing root incidence at zero lift
+    pub section_lift_slope: f32,  // per rad, two-dimensional
+    pub lift_slope: f32,  // per rad, finite wing
+    pub cl_max: f32,
+    pub stall_angle: f32,  // deg, wing
+    pub linear_fraction: f32,  // Share of CLmax reached on the straight line
+    pub post_stall_slope: f32,  // Per deg
+}
+
+impl LiftCurve {
+    pub fn from_params(params: &FlightParams) -> Self {
+        Self::from_section(params, &SectionPolar::from_params(params))
+    }
+
+    pub fn from_section(params: &FlightParams, section: &SectionPolar) -> Self {
+        let cl_max = section.cl_max.max(0.1);
+        let (section_lift_slope, section_zero_lift_angle) = fit_linear_range(section).unwrap_or_else(|| {
+            // Thin-airfoil slope corrected for thickness and the boundary layer
+            let slope = 2.0 * PI * (1.0 + 0.77 * params.wing_thickness_ratio) * 0.9;
+            let zero_lift_angle = params.airfoil.naca()
+                .map_or(params.airfoil_zero_lift_angle, |naca| naca.thin_airfoil_estimate().zero_lift_angle);
+            (slope, zero_lift_angle)
+        });
+
+        // The section's own rounding between the straight line and CLmax carries over to the wing
+        let linear_fraction = if section.points.is_empty() {
+            DEFAULT_LINEAR_FRACTION
+        } else {
+            let rounding = section.alpha_cl_max - section_zero_lift_angle - cl_max / section_lift_slope.to_radians();
+            (1.0 - rounding * section_lift_slope.to_radians() / cl_max).clamp(0.5, 0.95)
+        };
+
+        let post_stall_slope = section
+            .points
+            .last()
+            .filter(|point| point.alpha > section.alpha_cl_max + 0.5)
+            .map_or(DEFAULT_POST_STALL_SLOPE, |point| {
+                ((cl_max - point.cl) / (point.alpha - section.alpha_cl_max)).max(0.0)
+            });
+
+        // Helmbold's finite-wing slope, valid from low to high aspect ratio; the span loading supplies
+        // the aspect ratio that gives the planform's own slope, so twist, taper and tandem downwash count
+        let span_loading = SpanLoading::from_params(params);
+        let aspect_ratio = span_loading.effective_aspect_ratio();
+        let ratio = section_lift_slope / (PI * aspect_ratio.max(0.1));
+        let lift_slope = section_lift_slope / ((1.0 + ratio.powi(2)).sqrt() + ratio);
+
+        // Washout leaves the wing short of lift when the root sits at the section zero-lift angle
+        let zero_lift_angle = section_zero_lift_angle - (span_loading.zero_alpha_lift / span_loading.lift_slope.max(1e-6)).to_degrees();
+
+        // The wing is taken to reach the section CLmax, as the stall-speed estimate assumes
+        let stall_angle = zero_lift_angle + (2.0 - linear_fraction) * cl_max / lift_slope.to_radians();
+
+        Self {
+            zero_lift_angle,
+            section_lift_slope,
+            lift_slope,
+            cl_max,
+            stall_angle,
+            linear_fraction,
+            post_stall_slope,
+        }
+    }
+
+    // Angle of attack where the straight line ends and the rounding begins
+    fn knee_angle(&self) -> f32 {
+        self.zero_lift_angle + self.linear_fraction * self.cl_max / self.lift_slope.to_radians()
+    }
+
+    // Curvature of the parabola that meets the line tangentially and peaks at the stall angle
+    fn rounding(&self) -> f32 {
+        let slope = self.lift_slope.to_radians();
+        slope.powi(2) / (4.0 * (1.0 - self.linear_fraction) * self.cl_max)
+    }
+
+    pub fn lift_coefficient(&self, alpha: f32) -> f32 {
+        if alpha <= self.knee_angle() {
+            self.lift_slope.to_radians() * (alpha - self.zero_lift_angle)
+        } else if alpha <= self.stall_angle {
+            self.cl_max - self.rounding() * (self.stall_angle - alpha).powi(2)
+        } else {
+            (self.cl_max - self.post_stall_slope * (alpha - self.stall_angle)).max(POST_STALL_FLOOR * self.cl_max)
+        }
+    }
+
+    // Attached-flow angle of attack for a lift coefficient, None beyond CLmax
+    pub fn angle_for_lift(&self, cl: f32) -> Option<f32> {
+        if cl > self.cl_max {
+            None
+        } else if cl <= self.linear_fraction * self.cl_max {
+            Some(self.zero_lift_angle + cl / self.lift_slope.to_radians())
+        } else {
+            Some(self.stall_angle - ((self.cl_max - cl) / self.rounding()).sqrt())
+        }
+    }
+
+    pub fn stall_margin(&self, alpha: f32) -> f32 {
+        self.stall_angle - alpha
+    }
+}
+
+// Least-squares line through the attached points below 70% of CLmax: (slope per rad, zero-lift angle)
+fn fit_linear_range(section: &SectionPolar) -> Option<(f32, f32)> {
+    let points: Vec<(f32, f32)> = section
+        .points
+        .iter()
+        .filter(|point| point.alpha <= section.alpha_cl_max && point.cl <= 0.7 * section.cl_max)
+        .map(|point| (point.alpha, point.cl))
+        .collect();
+    if points.len() < 2 {
+        return None;
+    }
+    let count = points.len() as f32;
+    let mean_alpha = points.iter().map(|point| point.0).sum::<f32>() / count;
+    let mean_cl = points.iter().map(|point| point.1).sum::<f32>() / count;
+    let covariance: f32 = points.iter().map(|point| (point.0 - mean_alpha) * (point.1 - mean_cl)).sum();
+    let variance: f32 = points.iter().map(|point| (point.0 - mean_alpha).powi(2)).sum();
+    let slope = covariance / variance.max(1e-9);  // Per deg
+    if slope <= 0.0 {
+        return None;
+    }
+    Some((slope.to_degrees(), mean_alpha - mean_cl / slope))
+}
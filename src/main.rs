@@ -1,353 +1,121 @@
-use macroquad::prelude::*;
+use ascent::airfoil::{section_coordinates, section_thickness_ratio};
+use ascent::airfoil_import::import_airfoil;
+use ascent::config::{load_params, save_params, RecentFiles};
+use ascent::performance::{calculate_speed_sweep, SpeedSweep};
+use ascent::{
+    calculate_comprehensive_flight_analysis, flapping::stroke_phase, optimize_parameters, AirfoilSection, BatteryChemistry,
+    FlappingCycle, FlappingSample, FlightAnalysis,
+    FlightParams, FlightPhase, LiftCurve, MissionProfile, MissionSimulator, PilotProfile, PresetLibrary,
+    FairingType, LandingGear, PilotPosition, RunwaySurface, SpanLoading, WingLayout, WingPanel, WingPlanform,
+    Laminate, Material, MaterialLibrary, MaterialSource, Ply, PlyMaterial, SparSection,
+};
 use egui_macroquad::egui::{self, Color32, RichText, Stroke, Vec2 as EguiVec2};
-use egui_macroquad;
+use macroquad::prelude::*;
 use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 
-#[derive(Clone, Debug)]
-struct FlightParams {
-    pilot_mass: f32,
-    pilot_power_sustained: f32,
-    pilot_power_burst: f32,
-    
-    wing_count: u32,
-    wing_span: f32,
-    wing_chord: f32,
-    wing_thickness_ratio: f32,
-    
-    wing_material: WingMaterial,
-    spar_material: SparMaterial,
-    wing_safety_factor: f32,
-    
-    motor_power: f32,
-    motor_mass: f32,
-    battery_capacity: f32,
-    motor_efficiency: f32,
-    
-    airfoil_cl_max: f32,
-    airfoil_cd_min: f32,
-    oswald_efficiency: f32,
-    
-    forward_speed: f32,
-    flapping_frequency: f32,
-    flapping_amplitude: f32,
-    air_density: f32,
-    wind_speed: f32,
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum UITab {
+    Configuration,
+    Analysis,
+    Physics,
+    Optimization,
 }
 
-#[derive(Clone, Debug, PartialEq)]
-enum WingMaterial {
-    Fabric,
-    Carbon,
-    Wood,
-    Aluminum,
+struct SimulationState {
+    params: FlightParams,
+    analysis: FlightAnalysis,
+    history: HistoryData,
+    optimization_result: Option<FlightParams>,
+    camera_rotation: f32,
+    time: f32,
+    selected_preset: Option<String>,
+    presets: PresetLibrary,
+    new_preset: NewPresetForm,
+    materials: MaterialLibrary,
+    new_material: NewMaterialForm,
+    active_tab: UITab,
+    design_file: DesignFile,
+    airfoil_import: AirfoilImportForm,
+    naca: NacaForm,
+    mission: MissionSimulator,
+    mission_profile: MissionProfile,
+    mission_time_scale: f32,
 }
 
-#[derive(Clone, Debug, PartialEq)]
-enum SparMaterial {
-    Carbon,
-    Aluminum,
-    Wood,
-    Steel,
+struct NewPresetForm {
+    name: String,
+    description: String,
+    status: Option<(String, bool)>,  // message, is_error
 }
 
-#[derive(Clone, Debug, PartialEq)]
-enum FlightPreset {
-    Default,
-    UltralightGlider,
-    PoweredTakeoff,
-    SustainedFlight,
-    MaxEfficiency,
-    MinimalWeight,
-    RacingConfig,
+struct NewMaterialForm {
+    material: Material,
+    status: Option<(String, bool)>,  // message, is_error
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum UITab {
-    Configuration,
-    Analysis,
-    Physics,
-    Optimization,
+struct AirfoilImportForm {
+    coordinates_path: String,
+    polar_paths: String,  // Comma-separated, one XFOIL polar per Reynolds number
+    status: Option<(String, bool)>,  // message, is_error
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum FlightPhase {
-    OnGround,
-    Takeoff,
-    InFlight,
-    Landing,
+struct NacaForm {
+    designation: String,
+    status: Option<(String, bool)>,  // message, is_error
+}
+
+struct DesignFile {
+    current_path: Option<PathBuf>,
+    path_input: String,
+    recent: RecentFiles,
+    status: Option<(String, bool)>,  // message, is_error
 }
 
-impl FlightParams {
-    fn wing_area(&self) -> f32 {
-        self.wing_count as f32 * self.wing_span * self.wing_chord
+impl DesignFile {
+    fn new() -> Self {
+        Self {
+            current_path: None,
+            path_input: "design.toml".to_string(),
+            recent: RecentFiles::load(),
+            status: None,
+        }
     }
     
-    fn aspect_ratio(&self) -> f32 {
-        // For multiple wings, effective aspect ratio accounts for all wings
-        let single_wing_ar = self.wing_span / self.wing_chord;
-        if self.wing_count == 4 {
-            // Four wings have lower effective AR due to interference
-            single_wing_ar * 0.7
-        } else {
-            single_wing_ar
+    fn remember(&mut self, path: &Path) {
+        self.current_path = Some(path.to_path_buf());
+        self.path_input = path.display().to_string();
+        self.recent.push(path);
+        if let Err(err) = self.recent.save() {
+            self.status = Some((format!("Could not update recent files: {}", err), true));
         }
     }
     
-    fn from_preset(preset: FlightPreset) -> Self {
-        match preset {
-            FlightPreset::Default => Self::default(),
-            
-            FlightPreset::UltralightGlider => Self {
-                pilot_mass: 55.0,  // Very light pilot
-                pilot_power_sustained: 400.0,  // Elite cyclist level
-                pilot_power_burst: 1200.0,
-                wing_count: 2,
-                wing_span: 12.0,  // Much larger wings like Gossamer Albatross
-                wing_chord: 1.8,
-                wing_thickness_ratio: 0.08,
-                wing_material: WingMaterial::Fabric,
-                spar_material: SparMaterial::Carbon,
-                wing_safety_factor: 1.5,
-                motor_power: 0.0,
-                motor_mass: 0.0,
-                battery_capacity: 0.0,
-                motor_efficiency: 0.0,
-                airfoil_cl_max: 2.2,  // High-lift airfoil
-                airfoil_cd_min: 0.004,  // Very low drag
-                oswald_efficiency: 0.92,  // Excellent efficiency
-                forward_speed: 8.0,  // Slow but efficient
-                flapping_frequency: 0.0,
-                flapping_amplitude: 0.0,
-                air_density: 1.225,
-                wind_speed: 0.0,
-            },
-            
-            FlightPreset::PoweredTakeoff => Self {
-                pilot_mass: 70.0,
-                pilot_power_sustained: 350.0,
-                pilot_power_burst: 1000.0,
-                wing_count: 2,
-                wing_span: 10.0,  // Large wings for takeoff
-                wing_chord: 2.0,
-                wing_thickness_ratio: 0.10,
-                wing_material: WingMaterial::Carbon,
-                spar_material: SparMaterial::Carbon,
-                wing_safety_factor: 2.0,
-                motor_power: 8000.0,  // Strong motor for takeoff
-                motor_mass: 15.0,
-                battery_capacity: 2000.0,
-                motor_efficiency: 0.90,
-                airfoil_cl_max: 2.0,
-                airfoil_cd_min: 0.005,
-                oswald_efficiency: 0.88,
-                forward_speed: 12.0,
-                flapping_frequency: 0.0,
-                flapping_amplitude: 0.0,
-                air_density: 1.225,
-                wind_speed: 0.0,
-            },
-            
-            FlightPreset::SustainedFlight => Self {
-                pilot_mass: 50.0,  // Very light
-                pilot_power_sustained: 500.0,  // World-class endurance
-                pilot_power_burst: 1500.0,
-                wing_count: 2,
-                wing_span: 15.0,  // Massive wings
-                wing_chord: 1.5,
-                wing_thickness_ratio: 0.06,  // Very thin for efficiency
-                wing_material: WingMaterial::Carbon,
-                spar_material: SparMaterial::Carbon,
-                wing_safety_factor: 1.3,
-                motor_power: 3000.0,  // Significant motor assistance
-                motor_mass: 8.0,
-                battery_capacity: 3000.0,
-                motor_efficiency: 0.95,
-                airfoil_cl_max: 2.5,  // Very high-lift airfoil
-                airfoil_cd_min: 0.003,  // Extremely low drag
-                oswald_efficiency: 0.95,
-                forward_speed: 6.5,  // Very slow for efficiency
-                flapping_frequency: 0.0,
-                flapping_amplitude: 0.0,
-                air_density: 1.225,
-                wind_speed: 0.0,
-            },
-            
-            FlightPreset::MaxEfficiency => Self {
-                pilot_mass: 55.0,
-                pilot_power_sustained: 350.0,
-                pilot_power_burst: 1000.0,
-                wing_count: 2,
-                wing_span: 8.0,
-                wing_chord: 0.9,
-                wing_thickness_ratio: 0.08,
-                wing_material: WingMaterial::Carbon,
-                spar_material: SparMaterial::Carbon,
-                wing_safety_factor: 1.6,
-                motor_power: 1000.0,
-                motor_mass: 4.0,
-                battery_capacity: 600.0,
-                motor_efficiency: 0.92,
-                airfoil_cl_max: 2.0,
-                airfoil_cd_min: 0.004,
-                oswald_efficiency: 0.90,
-                forward_speed: 9.0,
-                flapping_frequency: 0.8,
-                flapping_amplitude: 15.0,
-                air_density: 1.225,
-                wind_speed: 4.0,
-            },
-            
-            FlightPreset::MinimalWeight => Self {
-                pilot_mass: 50.0,
-                pilot_power_sustained: 400.0,
-                pilot_power_burst: 1200.0,
-                wing_count: 2,
-                wing_span: 6.5,
-                wing_chord: 1.1,
-                wing_thickness_ratio: 0.07,
-                wing_material: WingMaterial::Fabric,
-                spar_material: SparMaterial::Carbon,
-                wing_safety_factor: 1.4,
-                motor_power: 800.0,
-                motor_mass: 3.0,
-                battery_capacity: 400.0,
-                motor_efficiency: 0.85,
-                airfoil_cl_max: 1.85,
-                airfoil_cd_min: 0.0055,
-                oswald_efficiency: 0.86,
-                forward_speed: 8.5,
-                flapping_frequency: 2.0,
-                flapping_amplitude: 25.0,
-                air_density: 1.225,
-                wind_speed: 5.0,
-            },
-            
-            FlightPreset::RacingConfig => Self {
-                pilot_mass: 70.0,
-                pilot_power_sustained: 450.0,
-                pilot_power_burst: 1500.0,
-                wing_count: 2,
-                wing_span: 5.0,
-                wing_chord: 1.8,
-                wing_thickness_ratio: 0.14,
-                wing_material: WingMaterial::Aluminum,
-                spar_material: SparMaterial::Aluminum,
-                wing_safety_factor: 2.5,
-                motor_power: 5000.0,
-                motor_mass: 15.0,
-                battery_capacity: 1000.0,
-                motor_efficiency: 0.82,
-                airfoil_cl_max: 1.5,
-                airfoil_cd_min: 0.009,
-                oswald_efficiency: 0.75,
-                forward_speed: 20.0,
-                flapping_frequency: 0.0,
-                flapping_amplitude: 0.0,
-                air_density: 1.225,
-                wind_speed: -2.0,
-            },
+    fn load(&mut self, path: &Path, materials: &MaterialLibrary) -> Option<FlightParams> {
+        match load_params(path, materials) {
+            Ok(params) => {
+                self.status = Some((format!("Loaded {}", path.display()), false));
+                self.remember(path);
+                Some(params)
+            }
+            Err(err) => {
+                self.status = Some((format!("Load failed: {}", err), true));
+                None
+            }
         }
     }
-}
-
-impl Default for FlightParams {
-    fn default() -> Self {
-        Self {
-            pilot_mass: 80.0,
-            pilot_power_sustained: 200.0,
-            pilot_power_burst: 600.0,
-            
-            wing_count: 4,
-            wing_span: 3.0,
-            wing_chord: 1.5,
-            wing_thickness_ratio: 0.12,
-            
-            wing_material: WingMaterial::Fabric,
-            spar_material: SparMaterial::Carbon,
-            wing_safety_factor: 2.0,
-            
-            motor_power: 2000.0,
-            motor_mass: 8.0,
-            battery_capacity: 500.0,
-            motor_efficiency: 0.85,
-            
-            airfoil_cl_max: 1.6,
-            airfoil_cd_min: 0.008,
-            oswald_efficiency: 0.8,
-            
-            forward_speed: 12.0,
-            flapping_frequency: 2.0,
-            flapping_amplitude: 25.0,
-            air_density: 1.225,
-            wind_speed: 0.0,
+    
+    fn save(&mut self, path: &Path, params: &FlightParams) {
+        match save_params(path, params) {
+            Ok(()) => {
+                self.status = Some((format!("Saved {}", path.display()), false));
+                self.remember(path);
+            }
+            Err(err) => self.status = Some((format!("Save failed: {}", err), true)),
         }
     }
 }
 
-#[derive(Clone)]
-struct StructuralAnalysis {
-    wing_mass: f32,
-    spar_mass: f32,
-    total_structural_mass: f32,
-    max_load_factor: f32,
-    wing_deflection: f32,
-    critical_flutter_speed: f32,
-    structural_feasible: bool,
-}
-
-#[derive(Clone)]
-struct FlightAnalysis {
-    // Basic properties (independent of flight state)
-    total_mass: f32,
-    weight_force: f32,
-    wing_area: f32,
-    wing_loading: f32,
-    stall_speed: f32,
-    
-    // Flight-dependent values (only valid when airborne)
-    flight_phase: FlightPhase,
-    effective_airspeed: f32,
-    dynamic_pressure: f32,
-    current_lift_coefficient: f32,
-    current_drag_coefficient: f32,
-    
-    // Forces (calculated based on flight conditions)
-    lift_force: f32,
-    drag_force: f32,
-    
-    // Power requirements
-    power_to_overcome_drag: f32,
-    power_for_flapping: f32,
-    power_for_climb: f32,
-    total_power_required: f32,
-    power_loading: f32,
-    
-    // Flight capabilities
-    can_takeoff: bool,
-    can_sustain_level_flight: bool,
-    can_climb: f32,
-    
-    motor_flight_time: f32,
-    takeoff_distance: f32,
-    
-    structural: StructuralAnalysis,
-    
-    reynolds_number: f32,
-    flutter_margin: f32,
-}
-
-struct SimulationState {
-    params: FlightParams,
-    analysis: FlightAnalysis,
-    history: HistoryData,
-    optimization_running: bool,
-    optimization_result: Option<FlightParams>,
-    camera_rotation: f32,
-    time: f32,
-    selected_preset: FlightPreset,
-    active_tab: UITab,
-}
-
 struct HistoryData {
     power_history: VecDeque<f32>,
     lift_history: VecDeque<f32>,
@@ -384,277 +152,6 @@ impl HistoryData {
     }
 }
 
-fn calculate_structural_properties(params: &FlightParams) -> StructuralAnalysis {
-    let wing_area_single = params.wing_span * params.wing_chord;
-    
-    let (wing_density, wing_youngs_modulus) = match params.wing_material {
-        WingMaterial::Fabric => (200.0, 1_000_000.0),  // kg/m³, Pa
-        WingMaterial::Carbon => (1600.0, 150_000_000_000.0),  // kg/m³, Pa  
-        WingMaterial::Wood => (600.0, 10_000_000_000.0),  // kg/m³, Pa
-        WingMaterial::Aluminum => (2700.0, 70_000_000_000.0),  // kg/m³, Pa
-    };
-    
-    let (spar_density, spar_youngs_modulus) = match params.spar_material {
-        SparMaterial::Carbon => (1600.0, 150_000_000_000.0),  // kg/m³, Pa
-        SparMaterial::Aluminum => (2700.0, 70_000_000_000.0),  // kg/m³, Pa
-        SparMaterial::Wood => (500.0, 10_000_000_000.0),  // kg/m³, Pa
-        SparMaterial::Steel => (7850.0, 200_000_000_000.0),  // kg/m³, Pa
-    };
-    
-    let effective_thickness = params.wing_chord * params.wing_thickness_ratio;
-    let wing_skin_mass = wing_area_single * wing_density * effective_thickness * 0.01;  // kg, corrected thickness
-    
-    let spar_height = effective_thickness * 0.8;
-    let spar_width = 0.02;  // 2cm spar width
-    let spar_volume = params.wing_span * spar_height * spar_width;  // m³
-    let spar_mass = spar_volume * spar_density;  // kg, fixed unit consistency
-    let wing_mass = wing_skin_mass + spar_mass + 1.5;  // +1.5kg for ribs, hardware
-    
-    let total_structural_mass = wing_mass * params.wing_count as f32 + params.motor_mass;
-    
-    let dynamic_pressure = 0.5 * params.air_density * params.forward_speed.powi(2);
-    let max_lift_per_wing = params.airfoil_cl_max * dynamic_pressure * wing_area_single;
-    let total_weight = (params.pilot_mass + total_structural_mass) * 9.81;
-    let max_load_factor = (max_lift_per_wing * params.wing_count as f32) / total_weight;
-    
-    let moment_of_inertia = (spar_height.powi(3) * 0.02) / 12.0;
-    let distributed_load = max_lift_per_wing / params.wing_span;
-    
-    let effective_modulus = wing_youngs_modulus * 0.1 + spar_youngs_modulus * 0.9;  // Spar carries most load
-    let wing_deflection = (distributed_load * params.wing_span.powi(4)) / (8.0 * effective_modulus * moment_of_inertia);
-    
-    // Credible flutter speed based on wing stiffness and mass distribution
-    let flutter_parameter = (effective_modulus * moment_of_inertia) / (spar_density * wing_area_single * params.wing_span.powi(4));
-    let critical_flutter_speed = flutter_parameter.sqrt() * 20.0;  // Empirical scaling
-    
-    let structural_feasible = max_load_factor >= params.wing_safety_factor && 
-                            wing_deflection < params.wing_span * 0.1 &&
-                            critical_flutter_speed > params.forward_speed * 1.5;
-    
-    StructuralAnalysis {
-        wing_mass,
-        spar_mass,
-        total_structural_mass,
-        max_load_factor,
-        wing_deflection,
-        critical_flutter_speed,
-        structural_feasible,
-    }
-}
-
-fn calculate_comprehensive_flight_analysis(params: &FlightParams) -> FlightAnalysis {
-    let structural = calculate_structural_properties(params);
-    
-    // Basic properties that don't depend on flight state
-    let total_mass = params.pilot_mass + structural.total_structural_mass;
-    let weight_force = total_mass * 9.81;
-    let wing_area = params.wing_area();
-    let wing_loading = weight_force / wing_area;
-    
-    // Calculate stall speed (minimum speed needed for lift = weight)
-    let stall_speed = (2.0 * weight_force / (params.air_density * wing_area * params.airfoil_cl_max)).sqrt();
-    
-    // Determine flight phase based on speed and power
-    let effective_airspeed = (params.forward_speed - params.wind_speed).max(0.0);
-    let available_power = params.pilot_power_burst + params.motor_power * params.motor_efficiency;
-    
-    let flight_phase = if effective_airspeed < 1.0 {
-        FlightPhase::OnGround
-    } else if effective_airspeed < stall_speed * 1.1 && available_power > 1000.0 {
-        FlightPhase::Takeoff
-    } else if effective_airspeed >= stall_speed {
-        FlightPhase::InFlight
-    } else {
-        FlightPhase::OnGround
-    };
-    
-    // Calculate flight-dependent values
-    let (dynamic_pressure, current_lift_coefficient, current_drag_coefficient, lift_force, drag_force) = 
-    match flight_phase {
-        FlightPhase::OnGround => (0.0, 0.0, 0.0, 0.0, 0.0),
-        
-        FlightPhase::Takeoff => {
-            let q = 0.5 * params.air_density * effective_airspeed.powi(2);
-            
-            // During takeoff, use maximum lift coefficient with flapping boost
-            let flapping_boost = if params.flapping_frequency > 0.1 {
-                let reduced_frequency = params.flapping_frequency * params.wing_span / effective_airspeed.max(0.1);
-                1.0 + (reduced_frequency * 0.3 * (params.flapping_amplitude / 45.0)).min(0.8)
-            } else {
-                1.0
-            };
-            
-            let cl = params.airfoil_cl_max * flapping_boost;
-            let lift = cl * q * wing_area;
-            
-            // Induced drag with multiple wing penalty
-            let base_induced_drag = cl.powi(2) / (std::f32::consts::PI * params.aspect_ratio() * params.oswald_efficiency);
-            let multi_wing_penalty = if params.wing_count == 4 {
-                1.3  // 30% penalty for wing interference
-            } else {
-                1.0
-            };
-            let induced_drag_coeff = base_induced_drag * multi_wing_penalty;
-            let cd = params.airfoil_cd_min + induced_drag_coeff;
-            let drag = cd * q * wing_area;
-            
-            (q, cl, cd, lift, drag)
-        },
-        
-        FlightPhase::InFlight => {
-            let q = 0.5 * params.air_density * effective_airspeed.powi(2);
-            
-            // In flight, lift coefficient adjusts to maintain level flight (L = W)
-            let flapping_boost = if params.flapping_frequency > 0.1 {
-                let reduced_frequency = params.flapping_frequency * params.wing_span / effective_airspeed.max(0.1);
-                1.0 + (reduced_frequency * 0.3 * (params.flapping_amplitude / 45.0)).min(0.8)
-            } else {
-                1.0
-            };
-            
-            // Required CL for level flight: L = W
-            let required_cl = weight_force / (q * wing_area * flapping_boost);
-            let max_available_cl = params.airfoil_cl_max * flapping_boost;
-            let cl = required_cl.min(max_available_cl);
-            // If we can't generate enough lift, we're in a dive/descending flight
-            let lift = cl * q * wing_area * flapping_boost;
-            
-            // Induced drag with multiple wing penalty
-            let base_induced_drag = cl.powi(2) / (std::f32::consts::PI * params.aspect_ratio() * params.oswald_efficiency);
-            let multi_wing_penalty = if params.wing_count == 4 {
-                1.3  // 30% penalty for wing interference
-            } else {
-                1.0
-            };
-            let induced_drag_coeff = base_induced_drag * multi_wing_penalty;
-            let cd = params.airfoil_cd_min + induced_drag_coeff;
-            let drag = cd * q * wing_area;
-            
-            (q, cl, cd, lift, drag)
-        },
-        
-        FlightPhase::Landing => (0.0, 0.0, 0.0, 0.0, 0.0), // Not implemented
-    };
-    
-    // Power calculations
-    let power_to_overcome_drag = if flight_phase != FlightPhase::OnGround {
-        drag_force * effective_airspeed
-    } else {
-        0.0
-    };
-    
-    let power_for_flapping = if params.flapping_frequency > 0.1 && flight_phase != FlightPhase::OnGround {
-        // More realistic flapping power based on wing inertia and aerodynamic work
-        let wing_tip_velocity = params.flapping_frequency * params.wing_span * params.flapping_amplitude.to_radians();
-        let wing_inertia = structural.wing_mass * params.wing_span.powi(2) / 3.0;  // Simple beam approximation
-        let inertial_power = wing_inertia * (2.0 * std::f32::consts::PI * params.flapping_frequency).powi(2) * wing_tip_velocity;
-        inertial_power * params.wing_count as f32 * 0.1  // Efficiency factor
-    } else {
-        0.0
-    };
-    
-    let power_for_climb = if flight_phase == FlightPhase::InFlight {
-        // Power for climb = Weight × Climb_Rate
-        // Climb rate determined by excess lift converted to vertical velocity
-        let excess_lift = (lift_force - weight_force).max(0.0);
-        let climb_rate = excess_lift / weight_force * effective_airspeed * 0.1;  // Small angle approximation
-        weight_force * climb_rate
-    } else {
-        0.0
-    };
-    
-    let total_power_required = power_to_overcome_drag + power_for_flapping + power_for_climb;
-    let power_loading = if total_power_required > 0.0 { 
-        total_power_required / weight_force 
-    } else { 
-        0.0 
-    };
-    
-    // Flight capabilities
-    let can_takeoff = available_power > total_power_required * 1.3 && 
-                     structural.structural_feasible &&
-                     effective_airspeed > stall_speed * 0.8;
-    
-    // Sustained flight includes motor assistance
-    let sustained_power_available = params.pilot_power_sustained + 
-        (params.motor_power * params.motor_efficiency);
-    let can_sustain_level_flight = sustained_power_available > total_power_required && 
-                                  effective_airspeed > stall_speed &&
-                                  structural.structural_feasible;
-    
-    // Climb rate calculation with proper power balance
-    let excess_power = sustained_power_available - total_power_required;
-    let can_climb = if excess_power > 0.0 { 
-        excess_power / weight_force  // Climb rate in m/s
-    } else { 
-        -1.0  // Descent rate if insufficient power
-    };
-    
-    // Battery endurance based on actual motor power draw, not rated power
-    let actual_motor_power_draw = if flight_phase != FlightPhase::OnGround {
-        (params.motor_power * params.motor_efficiency).min(total_power_required - params.pilot_power_sustained.max(0.0))
-    } else {
-        0.0
-    };
-    let motor_flight_time = if actual_motor_power_draw > 0.0 {
-        (params.battery_capacity / (actual_motor_power_draw / 1000.0)) * 60.0
-    } else {
-        f32::INFINITY
-    };
-    
-    // Proper takeoff distance calculation with ground roll
-    let takeoff_distance = if flight_phase == FlightPhase::Takeoff {
-        let lift_off_speed = stall_speed * 1.2;  // Need 20% margin above stall
-        // Ground roll dynamics: thrust must overcome rolling resistance + drag
-        let ground_drag = 0.02 * weight_force;  // Rolling resistance
-        let net_thrust = available_power / effective_airspeed.max(1.0) - ground_drag;
-        let acceleration = net_thrust / total_mass;
-        if acceleration > 0.1 {
-            lift_off_speed.powi(2) / (2.0 * acceleration)
-        } else {
-            f32::INFINITY
-        }
-    } else {
-        f32::INFINITY
-    };
-    
-    let chord_length = params.wing_chord;
-    let reynolds_number = if effective_airspeed > 0.0 { 
-        effective_airspeed * chord_length / 1.5e-5 
-    } else { 
-        0.0 
-    };
-    let flutter_margin = structural.critical_flutter_speed / effective_airspeed.max(1.0);
-    
-    FlightAnalysis {
-        total_mass,
-        weight_force,
-        wing_area,
-        wing_loading,
-        stall_speed,
-        flight_phase,
-        effective_airspeed,
-        dynamic_pressure,
-        current_lift_coefficient,
-        current_drag_coefficient,
-        lift_force,
-        drag_force,
-        power_to_overcome_drag,
-        power_for_flapping,
-        power_for_climb,
-        total_power_required,
-        power_loading,
-        can_takeoff,
-        can_sustain_level_flight,
-        can_climb,
-        motor_flight_time,
-        takeoff_distance,
-        structural,
-        reynolds_number,
-        flutter_margin,
-    }
-}
-
 fn draw_main_visualization(state: &SimulationState) {
     let main_area_x = 400.0;
     let main_area_y = 0.0;
@@ -664,8 +161,9 @@ fn draw_main_visualization(state: &SimulationState) {
     draw_rectangle(main_area_x, main_area_y, main_area_width, main_area_height, 
         Color::from_rgba(240, 245, 250, 255));
     
+    let mission = state.mission.state();
+    let flight_phase = mission.flight_phase();
     let center_x = main_area_x + main_area_width / 2.0;
-    let center_y = main_area_height / 2.0;
     
     // Ground indicator
     let ground_y = main_area_height - 80.0;
@@ -673,18 +171,30 @@ fn draw_main_visualization(state: &SimulationState) {
         3.0, Color::from_rgba(100, 80, 60, 255));
     draw_text("GROUND", main_area_x + 20.0, ground_y - 10.0, 16.0, Color::from_rgba(100, 80, 60, 255));
     
-    // Keep aircraft visible at consistent height
-    let visual_y = match state.analysis.flight_phase {
-        FlightPhase::OnGround => center_y + 150.0,
-        FlightPhase::Takeoff => center_y + 100.0,
-        FlightPhase::InFlight => center_y,
-        FlightPhase::Landing => center_y + 120.0,
-    };
+    // Distance markers scroll past so the camera can follow the aircraft
+    let marker_spacing = 10.0;  // m
+    let pixels_per_metre_ground = 12.0;
+    let first_marker = ((mission.distance - (center_x - main_area_x) / pixels_per_metre_ground) / marker_spacing).ceil() as i32;
+    let last_marker = ((mission.distance + (main_area_x + main_area_width - center_x) / pixels_per_metre_ground) / marker_spacing).floor() as i32;
+    for marker in first_marker..=last_marker {
+        let x = center_x + (marker as f32 * marker_spacing - mission.distance) * pixels_per_metre_ground;
+        draw_line(x, ground_y, x, ground_y + 10.0, 2.0, Color::from_rgba(100, 80, 60, 255));
+        if marker % 5 == 0 {
+            draw_text(&format!("{}m", marker as f32 * marker_spacing), x + 3.0, ground_y + 24.0, 14.0, 
+                Color::from_rgba(100, 80, 60, 255));
+        }
+    }
+    
+    // Altitude scale fits the cruise altitude into the view
+    let wheel_height = 60.0;
+    let view_altitude = (state.mission.profile().cruise_altitude * 1.3).max(5.0);
+    let pixels_per_metre = (ground_y - wheel_height - 120.0) / view_altitude;
+    let visual_y = ground_y - wheel_height - mission.altitude.min(view_altitude) * pixels_per_metre;
     
     let wing_scale = 35.0;
     let rotation = state.camera_rotation;
     
-    let pilot_color = match state.analysis.flight_phase {
+    let pilot_color = match flight_phase {
         FlightPhase::OnGround => Color::from_rgba(100, 100, 100, 180),
         FlightPhase::Takeoff => Color::from_rgba(200, 200, 50, 180),
         FlightPhase::InFlight => Color::from_rgba(50, 200, 50, 180),
@@ -694,20 +204,24 @@ fn draw_main_visualization(state: &SimulationState) {
     draw_circle(center_x, visual_y, 25.0, pilot_color);
     draw_text("PILOT", center_x - 20.0, visual_y + 5.0, 16.0, WHITE);
     
-    let flap_angle = if state.analysis.flight_phase != FlightPhase::OnGround {
-        (state.time * state.params.flapping_frequency * 2.0 * std::f32::consts::PI).sin() 
-        * state.params.flapping_amplitude.to_radians()
+    // Each pair follows the stroke kinematics, lagging the pair ahead by the phase offset; the wings
+    // are held still on the runway, on the approach and whenever the pilot cannot pay for the stroke
+    let flap_angle = |pair: u32| if mission.flapping {
+        let fraction = mission.time * state.params.flapping_frequency - pair as f32 * state.params.flapping_phase_offset / 360.0;
+        stroke_phase(fraction, state.params.flapping_upstroke_ratio).0.cos() * state.params.flapping_amplitude.to_radians()
     } else {
         0.0
     };
     
+    let planform = state.params.planform();
     for i in 0..state.params.wing_count {
+        let flap_angle = flap_angle(i / 2);
         let angle = rotation + (i as f32 * 2.0 * std::f32::consts::PI / state.params.wing_count as f32);
         let wing_x = center_x + angle.cos() * 40.0;
         let wing_y = visual_y + angle.sin() * 20.0;
         
-        let wing_end_x = wing_x + angle.cos() * state.params.wing_span * wing_scale;
-        let wing_end_y = wing_y + angle.sin() * state.params.wing_span * wing_scale * 0.3 
+        let wing_end_x = wing_x + angle.cos() * planform.span() * wing_scale;
+        let wing_end_y = wing_y + angle.sin() * planform.span() * wing_scale * 0.3 
             + flap_angle.sin() * 30.0;
         
         let wing_color = if state.analysis.structural.structural_feasible {
@@ -717,18 +231,17 @@ fn draw_main_visualization(state: &SimulationState) {
         };
         
         draw_line(wing_x, wing_y, wing_end_x, wing_end_y, 
-            state.params.wing_chord * 12.0, wing_color);
+            planform.mean_chord() * 12.0, wing_color);
         
         draw_line(wing_x, wing_y, wing_end_x, wing_end_y, 3.0, 
             Color::from_rgba(50, 50, 100, 255));
     }
     
-    // Force visualization (only when airborne)
-    if state.analysis.flight_phase != FlightPhase::OnGround {
-        let force_scale = 0.08;
-        
+    // Force visualization from the simulated forces
+    let force_scale = 0.08;
+    if mission.lift > 0.0 {
         // Lift arrow (green, pointing up)
-        let lift_height = state.analysis.lift_force * force_scale;
+        let lift_height = mission.lift * force_scale;
         draw_line(center_x, visual_y - 40.0, center_x, visual_y - 40.0 - lift_height, 
             4.0, Color::from_rgba(50, 200, 50, 255));
         draw_triangle(
@@ -737,9 +250,13 @@ fn draw_main_visualization(state: &SimulationState) {
             vec2(center_x + 8.0, visual_y - 40.0 - lift_height),
             Color::from_rgba(50, 200, 50, 255)
         );
-        
+        draw_text(&format!("Lift: {:.0}N", mission.lift), 
+            center_x + 30.0, visual_y - 60.0, 16.0, Color::from_rgba(50, 200, 50, 255));
+    }
+    
+    if mission.drag > 0.0 {
         // Drag arrow (blue, pointing left)
-        let drag_width = state.analysis.drag_force * force_scale * 2.0;
+        let drag_width = mission.drag * force_scale * 2.0;
         draw_line(center_x - 60.0, visual_y, center_x - 60.0 - drag_width, visual_y, 
             4.0, Color::from_rgba(50, 50, 200, 255));
         draw_triangle(
@@ -748,16 +265,28 @@ fn draw_main_visualization(state: &SimulationState) {
             vec2(center_x - 60.0 - drag_width, visual_y + 8.0),
             Color::from_rgba(50, 50, 200, 255)
         );
-        
-        draw_text(&format!("Lift: {:.0}N", state.analysis.lift_force), 
-            center_x + 30.0, visual_y - 60.0, 16.0, Color::from_rgba(50, 200, 50, 255));
-        draw_text(&format!("Drag: {:.0}N", state.analysis.drag_force), 
+        draw_text(&format!("Drag: {:.0}N", mission.drag), 
             center_x - 180.0, visual_y - 15.0, 16.0, Color::from_rgba(50, 50, 200, 255));
     }
     
+    if mission.thrust > 0.0 {
+        // Thrust arrow (orange, pointing right)
+        let thrust_width = mission.thrust * force_scale * 2.0;
+        draw_line(center_x + 60.0, visual_y, center_x + 60.0 + thrust_width, visual_y, 
+            4.0, Color::from_rgba(220, 140, 30, 255));
+        draw_triangle(
+            vec2(center_x + 65.0 + thrust_width, visual_y),
+            vec2(center_x + 60.0 + thrust_width, visual_y - 8.0),
+            vec2(center_x + 60.0 + thrust_width, visual_y + 8.0),
+            Color::from_rgba(220, 140, 30, 255)
+        );
+        draw_text(&format!("Thrust: {:.0}N", mission.thrust), 
+            center_x + 70.0, visual_y + 25.0, 16.0, Color::from_rgba(220, 140, 30, 255));
+    }
+    
     // Weight arrow (always present, pointing down)
-    let force_scale = 0.08;
-    let weight_height = state.analysis.weight_force * force_scale;
+    let weight = state.mission.weight();
+    let weight_height = weight * force_scale;
     draw_line(center_x, visual_y + 40.0, center_x, visual_y + 40.0 + weight_height, 
         4.0, Color::from_rgba(200, 50, 50, 255));
     draw_triangle(
@@ -766,18 +295,18 @@ fn draw_main_visualization(state: &SimulationState) {
         vec2(center_x + 8.0, visual_y + 40.0 + weight_height),
         Color::from_rgba(200, 50, 50, 255)
     );
-    draw_text(&format!("Weight: {:.0}N", state.analysis.weight_force), 
+    draw_text(&format!("Weight: {:.0}N", weight), 
         center_x + 30.0, visual_y + 60.0, 16.0, Color::from_rgba(200, 50, 50, 255));
     
     // Flight phase indicator
-    let phase_text = match state.analysis.flight_phase {
+    let phase_text = match flight_phase {
         FlightPhase::OnGround => "🔧 ON GROUND",
         FlightPhase::Takeoff => "🛫 TAKEOFF",
         FlightPhase::InFlight => "✈️ IN FLIGHT",
         FlightPhase::Landing => "🛬 LANDING",
     };
     
-    let phase_color = match state.analysis.flight_phase {
+    let phase_color = match flight_phase {
         FlightPhase::OnGround => Color::from_rgba(100, 100, 100, 255),
         FlightPhase::Takeoff => Color::from_rgba(200, 200, 50, 255),
         FlightPhase::InFlight => Color::from_rgba(50, 200, 50, 255),
@@ -785,13 +314,586 @@ fn draw_main_visualization(state: &SimulationState) {
     };
     
     draw_text(phase_text, main_area_x + 20.0, 30.0, 24.0, phase_color);
+    draw_text(&format!("Mission: {:?}", mission.phase), main_area_x + 20.0, 55.0, 18.0, phase_color);
     
+    let battery_text = if state.params.motor_power > 0.0 && state.params.battery_capacity > 0.0 {
+        format!("{:.0}%", state.mission.battery_fraction() * 100.0)
+    } else {
+        "-".to_string()
+    };
+    draw_text(&format!("t: {:.1}s | Alt: {:.1}m | Dist: {:.0}m | Battery: {} | Pilot Fatigue: {:.0}%", 
+        mission.time, mission.altitude, mission.distance, battery_text, mission.pilot_fatigue * 100.0),
+        main_area_x + 20.0, 80.0, 16.0, Color::from_rgba(60, 60, 80, 255));
+    
+    if state.mission.is_finished() {
+        let result = state.mission.result(Vec::new());
+        let (message, color) = match &result.abort_reason {
+            Some(reason) => (format!("Mission ended: {}", reason), Color::from_rgba(200, 50, 50, 255)),
+            None => (format!("Mission complete: {:.0} m in {:.0} s airborne", result.total_distance, result.flight_time), 
+                Color::from_rgba(50, 150, 50, 255)),
+        };
+        draw_text(&message, main_area_x + 20.0, 105.0, 18.0, color);
+    }
     
     draw_text(&format!("Mass: {:.0}kg | Speed: {:.1}m/s | Stall: {:.1}m/s", 
-        state.analysis.total_mass, state.analysis.effective_airspeed, state.analysis.stall_speed),
+        state.analysis.total_mass, mission.airspeed, state.mission.stall_speed()),
         main_area_x + 20.0, main_area_height - 30.0, 16.0, Color::from_rgba(60, 60, 80, 255));
 }
 
+fn draw_airfoil_import(ui: &mut egui::Ui, state: &mut SimulationState) {
+    ui.collapsing("Import Airfoil", |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Coordinates:");
+            ui.text_edit_singleline(&mut state.airfoil_import.coordinates_path);
+        });
+        ui.horizontal(|ui| {
+            ui.label("XFOIL Polars:");
+            ui.text_edit_singleline(&mut state.airfoil_import.polar_paths);
+        });
+        ui.label("Selig or Lednicer .dat; separate several polars with commas");
+        
+        let form = &state.airfoil_import;
+        let coordinates_path = PathBuf::from(form.coordinates_path.trim());
+        let polar_paths: Vec<PathBuf> = form.polar_paths
+            .split(',')
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .collect();
+        let ready = !coordinates_path.as_os_str().is_empty() && !polar_paths.is_empty();
+        
+        if ui.add_enabled(ready, egui::Button::new("Import")).clicked() {
+            state.airfoil_import.status = Some(match import_airfoil(&coordinates_path, &polar_paths) {
+                Ok(airfoil) => {
+                    let message = format!("Imported {} ({:.1}% thick, {} polars)", 
+                        airfoil.name, airfoil.thickness_ratio * 100.0, airfoil.polars.len());
+                    state.params.use_imported_airfoil(airfoil);
+                    (message, false)
+                }
+                Err(err) => (format!("Import failed: {}", err), true),
+            });
+        }
+        
+        if let Some((message, is_error)) = &state.airfoil_import.status {
+            let color = if *is_error { Color32::from_rgb(200, 50, 50) } else { Color32::from_rgb(50, 150, 50) };
+            ui.colored_label(color, message);
+        }
+    });
+}
+
+// Panel-by-panel planform; the first panel added starts from the single-panel wing
+fn draw_wing_panels(ui: &mut egui::Ui, params: &mut FlightParams) {
+    ui.collapsing("Wing Panels", |ui| {
+        let mut removed = None;
+        for (index, panel) in params.wing_panels.iter_mut().enumerate() {
+            ui.push_id(index, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(format!("Panel {}", index + 1)).strong());
+                    if ui.small_button("Remove").clicked() {
+                        removed = Some(index);
+                    }
+                });
+                ui.add(egui::Slider::new(&mut panel.span, 0.2..=8.0).text("Span").suffix(" m"));
+                ui.add(egui::Slider::new(&mut panel.root_chord, 0.1..=3.0).text("Root Chord").suffix(" m"));
+                ui.add(egui::Slider::new(&mut panel.tip_chord, 0.05..=3.0).text("Tip Chord").suffix(" m"));
+                ui.add(egui::Slider::new(&mut panel.sweep, -10.0..=40.0).text("Sweep").suffix("°"));
+                ui.add(egui::Slider::new(&mut panel.dihedral, -10.0..=30.0).text("Dihedral").suffix("°"));
+                ui.add(egui::Slider::new(&mut panel.twist, -5.0..=8.0).text("Washout").suffix("°"));
+            });
+        }
+        if let Some(index) = removed {
+            params.wing_panels.remove(index);
+        }
+        
+        if ui.button("Add outboard panel").clicked() {
+            if params.wing_panels.is_empty() {
+                params.wing_panels = params.planform().panels;
+            }
+            if let Some(last) = params.wing_panels.last().cloned() {
+                params.wing_panels.push(WingPanel {
+                    span: 0.5 * last.span,
+                    root_chord: last.tip_chord,
+                    tip_chord: 0.6 * last.tip_chord,
+                    ..last
+                });
+            }
+        }
+        
+        if params.wing_panels.is_empty() {
+            ui.label("Single panel from the sliders above");
+        } else {
+            // Keep the summary values in step for the optimizer and saved designs
+            let planform = params.planform();
+            params.wing_span = planform.span();
+            params.wing_chord = planform.mean_chord();
+        }
+    });
+}
+
+// Built-in database sections carry estimated polars, which the hover text says
+fn draw_tabulated_airfoil(ui: &mut egui::Ui, airfoil: &mut AirfoilSection, section: AirfoilSection, label: &str) {
+    let hover = section.data().map_or(String::new(), |data| data.description.clone());
+    ui.selectable_value(airfoil, section, label).on_hover_text(hover);
+}
+
+fn draw_material_picker(ui: &mut egui::Ui, label: &str, material: &mut Material, library: &MaterialLibrary) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        egui::ComboBox::from_id_salt(label)
+            .selected_text(material.name.as_str())
+            .show_ui(ui, |ui| {
+                for (entry, source) in &library.materials {
+                    let text = match source {
+                        MaterialSource::BuiltIn => entry.name.clone(),
+                        MaterialSource::User(_) => format!("{} (user)", entry.name),
+                    };
+                    let response = ui.selectable_label(material.name == entry.name, text).on_hover_text(format!(
+                        "{}\n{:.0} kg/m³, E {:.1} GPa, σ {:.0}/{:.0} MPa, ${:.0}/kg",
+                        entry.description, entry.density, entry.modulus,
+                        entry.tensile_strength, entry.compressive_strength, entry.cost_per_kg
+                    ));
+                    if response.clicked() {
+                        *material = entry.clone();
+                    }
+                }
+            });
+    });
+    ui.label(format!("  {:.0} kg/m³, E/ρ {:.1} MJ/kg, σ/ρ {:.0} kJ/kg, ${:.0}/kg",
+        material.density, material.specific_modulus() / 1e6, material.specific_strength() / 1e3, material.cost_per_kg));
+}
+
+fn draw_new_material(ui: &mut egui::Ui, library: &mut MaterialLibrary, form: &mut NewMaterialForm) {
+    ui.collapsing("Add Material", |ui| {
+        let material = &mut form.material;
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut material.name);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Description:");
+            ui.text_edit_singleline(&mut material.description);
+        });
+        ui.add(egui::Slider::new(&mut material.density, 10.0..=8000.0).logarithmic(true).text("Density").suffix(" kg/m³"));
+        ui.add(egui::Slider::new(&mut material.modulus, 0.001..=300.0).logarithmic(true).text("Modulus").suffix(" GPa"));
+        ui.add(egui::Slider::new(&mut material.tensile_strength, 0.1..=3000.0).logarithmic(true).text("Tensile").suffix(" MPa"));
+        ui.add(egui::Slider::new(&mut material.compressive_strength, 0.1..=3000.0).logarithmic(true).text("Compressive").suffix(" MPa"));
+        ui.add(egui::Slider::new(&mut material.shear_strength, 0.1..=1000.0).logarithmic(true).text("Shear").suffix(" MPa"));
+        ui.add(egui::Slider::new(&mut material.fatigue_limit, 0.05..=1500.0).logarithmic(true).text("Fatigue Limit").suffix(" MPa"));
+        ui.add(egui::Slider::new(&mut material.cost_per_kg, 0.5..=500.0).logarithmic(true).text("Cost").suffix(" $/kg"));
+        
+        let name = material.name.trim().to_string();
+        if library.get(&name).is_some_and(|(_, source)| *source == MaterialSource::BuiltIn) {
+            ui.label("A user material with this name will replace the built-in one");
+        }
+        
+        if ui.add_enabled(!name.is_empty(), egui::Button::new("💾 Save Material")).clicked() {
+            form.status = Some(match library.save_user_material(material) {
+                Ok(path) => (format!("Saved {}", path.display()), false),
+                Err(err) => (format!("Save failed: {}", err), true),
+            });
+        }
+        
+        for error in &library.load_errors {
+            ui.colored_label(Color32::from_rgb(200, 50, 50), format!("Material error: {}", error));
+        }
+        if let Some((message, is_error)) = &form.status {
+            let color = if *is_error { Color32::from_rgb(200, 50, 50) } else { Color32::from_rgb(50, 150, 50) };
+            ui.colored_label(color, message);
+        }
+    });
+}
+
+fn draw_spar_layup(ui: &mut egui::Ui, params: &mut FlightParams) {
+    ui.collapsing("Spar Layup", |ui| {
+        let mut removed = None;
+        for (index, ply) in params.spar_layup.iter_mut().enumerate() {
+            ui.push_id(index, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(format!("Ply {}", index + 1)).strong());
+                    if ui.small_button("Remove").clicked() {
+                        removed = Some(index);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut ply.material, PlyMaterial::CarbonUd, "Carbon UD");
+                    ui.selectable_value(&mut ply.material, PlyMaterial::CarbonFabric, "Carbon Fabric");
+                });
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut ply.material, PlyMaterial::GlassFabric, "Glass Fabric");
+                    ui.selectable_value(&mut ply.material, PlyMaterial::AramidUd, "Aramid UD");
+                });
+                ui.add(egui::Slider::new(&mut ply.thickness, 0.05..=2.0).text("Thickness").suffix(" mm"));
+                ui.add(egui::Slider::new(&mut ply.angle, -90.0..=90.0).text("Angle").suffix("°"));
+            });
+        }
+        if let Some(index) = removed {
+            params.spar_layup.remove(index);
+        }
+        
+        if ui.button("Add ply").clicked() {
+            let ply = params.spar_layup.last().cloned().unwrap_or(Ply {
+                material: PlyMaterial::CarbonUd,
+                thickness: 0.25,
+                angle: 0.0,
+            });
+            params.spar_layup.push(ply);
+        }
+        
+        match Laminate::from_plies(&params.spar_layup) {
+            Some(laminate) => {
+                ui.label(format!("Wall {:.2} mm, {:.0} kg/m³", laminate.thickness * 1000.0, laminate.density));
+                ui.label(format!("Ex {:.1} GPa, Gxy {:.1} GPa, bending {:.1} GPa",
+                    laminate.axial_modulus / 1e9, laminate.shear_modulus / 1e9, laminate.bending_modulus / 1e9));
+            }
+            None => {
+                ui.label("No plies: spar uses the material above");
+            }
+        }
+    });
+}
+
+// Top view of one wing, root on the left, leading edge up
+fn draw_planform(ui: &mut egui::Ui, planform: &WingPlanform) {
+    let width = ui.available_width().min(300.0);
+    let response = ui.allocate_response(EguiVec2::new(width, width * 0.35), egui::Sense::hover());
+    let painter = ui.painter_at(response.rect);
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, Stroke::new(1.0, Color32::from_rgb(200, 200, 200)), egui::StrokeKind::Inside);
+    
+    // Leading and trailing edge at each panel break, x positive aft
+    let mut breaks = vec![planform.station(0.0)];
+    let mut y = 0.0;
+    for panel in &planform.panels {
+        y += panel.projected_span();
+        breaks.push(planform.station(y));
+    }
+    let edges: Vec<(f32, f32, f32)> = breaks
+        .iter()
+        .zip(std::iter::once(planform.root_chord()).chain(planform.panels.iter().map(|panel| panel.tip_chord)))
+        .map(|(station, chord)| (station.y, station.x - 0.25 * chord, station.x + 0.75 * chord))
+        .collect();
+    let x_min = edges.iter().map(|edge| edge.1).fold(f32::INFINITY, f32::min);
+    let x_max = edges.iter().map(|edge| edge.2).fold(f32::NEG_INFINITY, f32::max);
+    let y_max = edges.last().map_or(1.0, |edge| edge.0).max(1e-3);
+    let scale = ((rect.width() - 10.0) / y_max).min((rect.height() - 10.0) / (x_max - x_min).max(1e-3));
+    let to_screen = |y: f32, x: f32| egui::Pos2::new(rect.left() + 5.0 + y * scale, rect.top() + 5.0 + (x - x_min) * scale);
+    
+    let outline = Stroke::new(1.5, Color32::from_rgb(50, 100, 200));
+    for window in edges.windows(2) {
+        let (inner, outer) = (window[0], window[1]);
+        painter.line_segment([to_screen(inner.0, inner.1), to_screen(outer.0, outer.1)], outline);
+        painter.line_segment([to_screen(inner.0, inner.2), to_screen(outer.0, outer.2)], outline);
+        painter.line_segment([to_screen(outer.0, outer.1), to_screen(outer.0, outer.2)], Stroke::new(1.0, Color32::from_rgb(150, 150, 150)));
+    }
+    if let Some(root) = edges.first() {
+        painter.line_segment([to_screen(root.0, root.1), to_screen(root.0, root.2)], outline);
+    }
+}
+
+fn draw_naca_generator(ui: &mut egui::Ui, state: &mut SimulationState) {
+    ui.horizontal(|ui| {
+        ui.label("NACA:");
+        let response = ui.add(egui::TextEdit::singleline(&mut state.naca.designation).desired_width(60.0));
+        let submitted = response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+        if ui.button("Generate").clicked() || submitted {
+            state.naca.status = match state.params.use_naca_airfoil(&state.naca.designation) {
+                Ok(()) => None,
+                Err(err) => Some((err, true)),
+            };
+        }
+    });
+    ui.label("4-digit (2412) or 5-digit (23012) designation");
+    
+    if let Some((message, is_error)) = &state.naca.status {
+        let color = if *is_error { Color32::from_rgb(200, 50, 50) } else { Color32::from_rgb(50, 150, 50) };
+        ui.colored_label(color, message);
+    }
+}
+
+// CL against α from below zero lift to well past the stall, with the operating point marked
+fn draw_lift_curve(ui: &mut egui::Ui, lift_curve: &LiftCurve, angle_of_attack: f32) {
+    let (alpha_min, alpha_max) = (lift_curve.zero_lift_angle - 4.0, lift_curve.stall_angle + 10.0);
+    let cl_max = lift_curve.cl_max * 1.15;
+    let cl_min = lift_curve.lift_coefficient(alpha_min).min(0.0);
+    
+    let response = ui.allocate_response(EguiVec2::new(300.0, 120.0), egui::Sense::hover());
+    let painter = ui.painter_at(response.rect);
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, Stroke::new(1.0, Color32::from_rgb(120, 120, 120)), egui::StrokeKind::Inside);
+    
+    let to_x = |alpha: f32| rect.left() + (alpha - alpha_min) / (alpha_max - alpha_min) * rect.width();
+    let to_y = |cl: f32| rect.bottom() - (cl - cl_min) / (cl_max - cl_min) * rect.height();
+    
+    let axis = Stroke::new(1.0, Color32::from_rgb(200, 200, 200));
+    painter.line_segment([egui::Pos2::new(rect.left(), to_y(0.0)), egui::Pos2::new(rect.right(), to_y(0.0))], axis);
+    painter.line_segment([egui::Pos2::new(to_x(0.0), rect.top()), egui::Pos2::new(to_x(0.0), rect.bottom())], axis);
+    
+    let stall_x = to_x(lift_curve.stall_angle);
+    painter.line_segment([egui::Pos2::new(stall_x, rect.top()), egui::Pos2::new(stall_x, rect.bottom())],
+        Stroke::new(1.0, Color32::from_rgb(150, 150, 150)));
+    painter.text(egui::Pos2::new(stall_x + 2.0, rect.top() + 2.0), egui::Align2::LEFT_TOP,
+        "stall", egui::FontId::proportional(9.0), Color32::from_rgb(150, 150, 150));
+    
+    let steps = 120;
+    let points: Vec<egui::Pos2> = (0..=steps)
+        .map(|i| {
+            let alpha = alpha_min + (alpha_max - alpha_min) * i as f32 / steps as f32;
+            egui::Pos2::new(to_x(alpha), to_y(lift_curve.lift_coefficient(alpha)))
+        })
+        .collect();
+    for window in points.windows(2) {
+        painter.line_segment([window[0], window[1]], Stroke::new(2.0, Color32::from_rgb(50, 100, 200)));
+    }
+    
+    let operating_point = egui::Pos2::new(to_x(angle_of_attack), to_y(lift_curve.lift_coefficient(angle_of_attack)));
+    painter.circle_filled(operating_point, 3.0, Color32::from_rgb(200, 50, 50));
+    
+    ui.label(format!("α {:.0}° to {:.0}°, CL {:.1} to {:.1}", alpha_min, alpha_max, cl_min, cl_max));
+}
+
+// Local section CL across the span, one line per lifting surface
+fn draw_span_loading(ui: &mut egui::Ui, span_loading: &SpanLoading, cl: f32) {
+    let colors = [Color32::from_rgb(50, 100, 200), Color32::from_rgb(200, 100, 50), Color32::from_rgb(50, 150, 50)];
+    let half_span = 0.5 * span_loading.reference_span.max(0.1);
+    let local: Vec<f32> = span_loading.stations.iter().map(|station| span_loading.local_lift_coefficient(station, cl)).collect();
+    let cl_top = local.iter().copied().fold(0.1, f32::max) * 1.15;
+    let cl_bottom = local.iter().copied().fold(0.0, f32::min);
+    
+    let response = ui.allocate_response(EguiVec2::new(300.0, 100.0), egui::Sense::hover());
+    let painter = ui.painter_at(response.rect);
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, Stroke::new(1.0, Color32::from_rgb(120, 120, 120)), egui::StrokeKind::Inside);
+    
+    let to_x = |y: f32| rect.center().x + y / half_span * 0.5 * rect.width();
+    let to_y = |value: f32| rect.bottom() - (value - cl_bottom) / (cl_top - cl_bottom) * rect.height();
+    
+    let axis = Stroke::new(1.0, Color32::from_rgb(200, 200, 200));
+    painter.line_segment([egui::Pos2::new(rect.left(), to_y(0.0)), egui::Pos2::new(rect.right(), to_y(0.0))], axis);
+    painter.line_segment([egui::Pos2::new(to_x(0.0), rect.top()), egui::Pos2::new(to_x(0.0), rect.bottom())], axis);
+    
+    for surface in 0..span_loading.planform.surfaces.len() {
+        let points: Vec<egui::Pos2> = span_loading
+            .stations
+            .iter()
+            .zip(&local)
+            .filter(|(station, _)| station.surface == surface)
+            .map(|(station, &value)| egui::Pos2::new(to_x(station.y), to_y(value)))
+            .collect();
+        for window in points.windows(2) {
+            painter.line_segment([window[0], window[1]], Stroke::new(2.0, colors[surface % colors.len()]));
+        }
+    }
+    
+    ui.label(format!("y = ±{:.1} m, local CL {:.2} to {:.2}", half_span, cl_bottom, cl_top));
+}
+
+// Lift and thrust (N, left scale) and power (W, right scale) over one stroke, downstroke shaded
+fn draw_flapping_cycle(ui: &mut egui::Ui, cycle: &FlappingCycle) {
+    if cycle.samples.is_empty() {
+        return;
+    }
+    let lift_color = Color32::from_rgb(50, 150, 50);
+    let thrust_color = Color32::from_rgb(200, 100, 50);
+    let power_color = Color32::from_rgb(50, 100, 200);
+    let force_top = cycle.samples.iter().map(|sample| sample.lift.max(sample.thrust)).fold(1.0, f32::max) * 1.1;
+    let force_bottom = cycle.samples.iter().map(|sample| sample.lift.min(sample.thrust)).fold(0.0, f32::min) * 1.1;
+    let power = |sample: &FlappingSample| sample.aerodynamic_power + sample.inertial_power;
+    let power_top = cycle.samples.iter().map(power).fold(1.0, f32::max) * 1.1;
+    let power_bottom = cycle.samples.iter().map(power).fold(0.0, f32::min) * 1.1;
+    
+    let response = ui.allocate_response(EguiVec2::new(300.0, 120.0), egui::Sense::hover());
+    let painter = ui.painter_at(response.rect);
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, Stroke::new(1.0, Color32::from_rgb(120, 120, 120)), egui::StrokeKind::Inside);
+    
+    let to_x = |phase: f32| rect.left() + phase * rect.width();
+    let to_force_y = |value: f32| rect.bottom() - (value - force_bottom) / (force_top - force_bottom) * rect.height();
+    let to_power_y = |value: f32| rect.bottom() - (value - power_bottom) / (power_top - power_bottom) * rect.height();
+    
+    // Downstroke of the front wings: from the top of the stroke until the flap angle turns back up
+    let bottom_of_stroke = cycle
+        .samples
+        .iter()
+        .min_by(|a, b| a.flap_angle.total_cmp(&b.flap_angle))
+        .map_or(0.5, |sample| sample.phase);
+    painter.rect_filled(
+        egui::Rect::from_min_max(egui::Pos2::new(to_x(0.0), rect.top()), egui::Pos2::new(to_x(bottom_of_stroke), rect.bottom())),
+        0.0,
+        Color32::from_rgba_unmultiplied(200, 200, 200, 40),
+    );
+    painter.line_segment([egui::Pos2::new(rect.left(), to_force_y(0.0)), egui::Pos2::new(rect.right(), to_force_y(0.0))],
+        Stroke::new(1.0, Color32::from_rgb(200, 200, 200)));
+    
+    // Close the loop so the curve runs to the end of the period
+    let mut samples: Vec<(f32, &FlappingSample)> = cycle.samples.iter().map(|sample| (sample.phase, sample)).collect();
+    samples.push((1.0, &cycle.samples[0]));
+    let draw_curve = |color: Color32, points: Vec<egui::Pos2>| {
+        for window in points.windows(2) {
+            painter.line_segment([window[0], window[1]], Stroke::new(2.0, color));
+        }
+    };
+    draw_curve(lift_color, samples.iter().map(|(phase, sample)| egui::Pos2::new(to_x(*phase), to_force_y(sample.lift))).collect());
+    draw_curve(thrust_color, samples.iter().map(|(phase, sample)| egui::Pos2::new(to_x(*phase), to_force_y(sample.thrust))).collect());
+    draw_curve(power_color, samples.iter().map(|(phase, sample)| egui::Pos2::new(to_x(*phase), to_power_y(power(sample)))).collect());
+    
+    ui.horizontal(|ui| {
+        ui.colored_label(lift_color, "Lift");
+        ui.colored_label(thrust_color, "Thrust");
+        ui.label(format!("{:.0} to {:.0} N", force_bottom, force_top));
+    });
+    ui.horizontal(|ui| {
+        ui.colored_label(power_color, "Power");
+        ui.label(format!("{:.0} to {:.0} W over one {:.2} s stroke", power_bottom, power_top, 1.0 / cycle.frequency.max(1e-6)));
+    });
+}
+
+// Section outline at true aspect, chord across the panel
+fn draw_airfoil_shape(ui: &mut egui::Ui, coordinates: &[[f32; 2]]) {
+    let width = ui.available_width().min(300.0);
+    let response = ui.allocate_response(EguiVec2::new(width, width * 0.3), egui::Sense::hover());
+    let painter = ui.painter_at(response.rect);
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, Stroke::new(1.0, Color32::from_rgb(200, 200, 200)), egui::StrokeKind::Inside);
+    
+    let x_min = coordinates.iter().map(|point| point[0]).fold(f32::INFINITY, f32::min);
+    let x_max = coordinates.iter().map(|point| point[0]).fold(f32::NEG_INFINITY, f32::max);
+    let scale = (rect.width() - 10.0) / (x_max - x_min).max(1e-6);
+    let to_screen = |point: &[f32; 2]| {
+        egui::Pos2::new(rect.left() + 5.0 + (point[0] - x_min) * scale, rect.center().y - point[1] * scale)
+    };
+    
+    painter.line_segment([egui::Pos2::new(rect.left(), rect.center().y), egui::Pos2::new(rect.right(), rect.center().y)],
+        Stroke::new(1.0, Color32::from_rgb(220, 220, 220)));
+    let outline: Vec<egui::Pos2> = coordinates.iter().map(to_screen).collect();
+    for window in outline.windows(2) {
+        painter.line_segment([window[0], window[1]], Stroke::new(1.5, Color32::from_rgb(50, 100, 200)));
+    }
+}
+
+fn draw_file_controls(ui: &mut egui::Ui, state: &mut SimulationState) {
+    ui.collapsing("Design File", |ui| {
+        ui.label(match &state.design_file.current_path {
+            Some(path) => format!("Current: {}", path.display()),
+            None => "Current: (unsaved)".to_string(),
+        });
+        
+        ui.horizontal(|ui| {
+            ui.label("Path:");
+            ui.text_edit_singleline(&mut state.design_file.path_input);
+        });
+        
+        let input_path = PathBuf::from(state.design_file.path_input.trim());
+        let has_input = !input_path.as_os_str().is_empty();
+        
+        ui.horizontal(|ui| {
+            if ui.button("💾 Save").clicked() {
+                // Save falls back to the typed path until the design has a file
+                let path = state.design_file.current_path.clone().unwrap_or_else(|| input_path.clone());
+                if !path.as_os_str().is_empty() {
+                    state.design_file.save(&path, &state.params);
+                }
+            }
+            if ui.add_enabled(has_input, egui::Button::new("Save As")).clicked() {
+                state.design_file.save(&input_path, &state.params);
+            }
+            if ui.add_enabled(has_input, egui::Button::new("📂 Load")).clicked() {
+                if let Some(params) = state.design_file.load(&input_path, &state.materials) {
+                    state.params = params;
+                    state.selected_preset = None;
+                }
+            }
+        });
+        ui.label("TOML or JSON, chosen by file extension");
+        
+        if let Some((message, is_error)) = &state.design_file.status {
+            let color = if *is_error { Color32::from_rgb(200, 50, 50) } else { Color32::from_rgb(50, 150, 50) };
+            ui.colored_label(color, message);
+        }
+        
+        if !state.design_file.recent.paths.is_empty() {
+            ui.separator();
+            ui.label(RichText::new("Recent Files").strong());
+            let mut selected = None;
+            for path in &state.design_file.recent.paths {
+                let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+                if ui.button(name).on_hover_text(path.display().to_string()).clicked() {
+                    selected = Some(path.clone());
+                }
+            }
+            if let Some(path) = selected {
+                if let Some(params) = state.design_file.load(&path, &state.materials) {
+                    state.params = params;
+                    state.selected_preset = None;
+                }
+            }
+        }
+    });
+}
+
+fn draw_preset_list(ui: &mut egui::Ui, state: &mut SimulationState) {
+    ui.heading("Flight Scenarios");
+    ui.label("Human-powered flight requires extreme efficiency:");
+    
+    let mut selected = None;
+    for preset in &state.presets.presets {
+        let is_current = state.selected_preset.as_deref() == Some(preset.name.as_str());
+        let label = if preset.is_builtin() {
+            preset.name.clone()
+        } else {
+            format!("{} (user)", preset.name)
+        };
+        if ui.selectable_label(is_current, RichText::new(label).strong()).clicked() {
+            selected = Some(preset.clone());
+        }
+        if !preset.description.is_empty() {
+            ui.label(format!("• {}", preset.description));
+        }
+    }
+    if let Some(preset) = selected {
+        state.params = preset.params;
+        state.selected_preset = Some(preset.name);
+    }
+    
+    for error in &state.presets.load_errors {
+        ui.colored_label(Color32::from_rgb(200, 50, 50), format!("Preset error: {}", error));
+    }
+    
+    ui.label(format!("Current: {}", state.selected_preset.as_deref().unwrap_or("Custom")));
+    
+    ui.collapsing("Save current as preset", |ui| {
+        let form = &mut state.new_preset;
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut form.name);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Description:");
+            ui.text_edit_singleline(&mut form.description);
+        });
+        
+        let name = form.name.trim().to_string();
+        let overwrites_builtin = state.presets.get(&name).is_some_and(|preset| preset.is_builtin());
+        if overwrites_builtin {
+            ui.label("A user preset with this name will replace the built-in one");
+        }
+        
+        if ui.add_enabled(!name.is_empty(), egui::Button::new("💾 Save Preset")).clicked() {
+            match state.presets.save_user_preset(&name, &form.description, &state.params) {
+                Ok(path) => {
+                    form.status = Some((format!("Saved {}", path.display()), false));
+                    state.selected_preset = Some(name);
+                }
+                Err(err) => form.status = Some((format!("Save failed: {}", err), true)),
+            }
+        }
+        
+        if let Some((message, is_error)) = &form.status {
+            let color = if *is_error { Color32::from_rgb(200, 50, 50) } else { Color32::from_rgb(50, 150, 50) };
+            ui.colored_label(color, message);
+        }
+    });
+}
+
 fn draw_physics_equations(ui: &mut egui::Ui, analysis: &FlightAnalysis, params: &FlightParams) {
     ui.heading("Flight Physics Equations");
     
@@ -805,7 +907,7 @@ fn draw_physics_equations(ui: &mut egui::Ui, analysis: &FlightAnalysis, params:
             ui.label("Lift: L = CL × ½ρV² × S");
             ui.label(format!("L = {:.3} × ½×{:.3}×{:.1}² × {:.1}", 
                 analysis.current_lift_coefficient, 
-                analysis.dynamic_pressure * 2.0 / analysis.effective_airspeed.powi(2).max(0.01), // actual air density
+                analysis.atmosphere.density,
                 analysis.effective_airspeed,
                 analysis.wing_area));
             ui.label(format!("L = {:.0} N", analysis.lift_force));
@@ -814,7 +916,7 @@ fn draw_physics_equations(ui: &mut egui::Ui, analysis: &FlightAnalysis, params:
             ui.label("Drag: D = CD × ½ρV² × S");
             ui.label(format!("D = {:.4} × ½×{:.3}×{:.1}² × {:.1}", 
                 analysis.current_drag_coefficient,
-                analysis.dynamic_pressure * 2.0 / analysis.effective_airspeed.powi(2).max(0.01), // actual air density
+                analysis.atmosphere.density,
                 analysis.effective_airspeed,
                 analysis.wing_area));
             ui.label(format!("D = {:.0} N", analysis.drag_force));
@@ -826,14 +928,103 @@ fn draw_physics_equations(ui: &mut egui::Ui, analysis: &FlightAnalysis, params:
     
     ui.separator();
     
+    ui.group(|ui| {
+        let atmosphere = &analysis.atmosphere;
+        ui.label(RichText::new("Atmosphere (ISA)").strong());
+        ui.label(format!("h = {:.0} m, ΔT = {:+.1} K, RH = {:.0}%", 
+            params.altitude, params.temperature_offset, params.relative_humidity * 100.0));
+        ui.label(format!("T = {:.1} K ({:.1} °C)", atmosphere.temperature, atmosphere.temperature_celsius()));
+        ui.label(format!("p = {:.0} Pa", atmosphere.pressure));
+        ui.label("Density: ρ = p_d/(R_d·T) + p_v/(R_v·T)");
+        ui.label(format!("ρ = {:.4} kg/m³ (σ = {:.3})", atmosphere.density, atmosphere.density_ratio()));
+        ui.label(format!("a = √(γRT) = {:.1} m/s", atmosphere.speed_of_sound));
+        ui.label("Sutherland: μ = C₁T^1.5/(T+S)");
+        ui.label(format!("μ = {:.3e} Pa·s, ν = {:.3e} m²/s", atmosphere.dynamic_viscosity, atmosphere.kinematic_viscosity));
+        ui.label(format!("Re = Vc/ν = {:.0}", analysis.reynolds_number));
+    });
+    
+    ui.separator();
+    
+    ui.group(|ui| {
+        let airfoil = &analysis.airfoil;
+        ui.label(RichText::new(format!("Airfoil: {}", airfoil.name)).strong());
+        if airfoil.points.is_empty() {
+            ui.label(format!("CLmax = {:.2}, CD₀ = {:.4} (fixed)", airfoil.cl_max, airfoil.cd_min));
+        } else {
+            if matches!(params.airfoil, AirfoilSection::Naca(_)) {
+                ui.label(format!("Estimated polar at Re = {:.0}", airfoil.reynolds));
+            } else {
+                ui.label(format!("Polar interpolated in log Re at Re = {:.0} ({})", airfoil.reynolds, params.airfoil.polar_source()));
+            }
+            ui.label(format!("CLmax = {:.2} at α = {:.1}°", airfoil.cl_max, airfoil.alpha_cl_max));
+            ui.label(format!("CDmin = {:.4}", airfoil.cd_min));
+            ui.label(format!("Profile drag at CL = {:.2}: CD = {:.4}", 
+                analysis.current_lift_coefficient, airfoil.profile_drag(analysis.current_lift_coefficient)));
+        }
+        if let Some(theory) = &analysis.thin_airfoil {
+            ui.separator();
+            ui.label("Thin-airfoil theory:");
+            ui.label(format!("α_L0 = {:.2}°, a₀ = {:.2}/rad", theory.zero_lift_angle, theory.lift_slope));
+            ui.label(format!("CL_ideal = {:.2}, Cm_c/4 = {:.3}", theory.ideal_lift_coefficient, theory.moment_coefficient));
+        }
+    });
+    
+    ui.separator();
+    
+    ui.group(|ui| {
+        let lift_curve = &analysis.lift_curve;
+        ui.label(RichText::new("Lift Curve").strong());
+        ui.label("Helmbold: a = a₀ / (√(1 + (a₀/πA)²) + a₀/πA)");
+        ui.label(format!("a₀ = {:.2}/rad, A_eff = {:.1} → a = {:.2}/rad", 
+            lift_curve.section_lift_slope, analysis.span_loading.effective_aspect_ratio(), lift_curve.lift_slope));
+        ui.label(format!("CL = a(α - α_L0), α_L0 = {:.2}°", lift_curve.zero_lift_angle));
+        ui.label(format!("Stall at α = {:.1}° (CLmax {:.2}), then -{:.3} CL/°", 
+            lift_curve.stall_angle, lift_curve.cl_max, lift_curve.post_stall_slope));
+        if analysis.flight_phase != FlightPhase::OnGround {
+            ui.label(format!("α = {:.2}°, stall margin {:.2}°", analysis.angle_of_attack, analysis.stall_margin));
+        }
+        draw_lift_curve(ui, lift_curve, analysis.angle_of_attack);
+    });
+    
+    ui.separator();
+    
+    ui.group(|ui| {
+        let span_loading = &analysis.span_loading;
+        let cl = analysis.current_lift_coefficient;
+        ui.label(RichText::new("Span Loading").strong());
+        ui.label(format!("Vortex lattice: {} surface(s), {} strips", 
+            span_loading.planform.surfaces.len(), span_loading.stations.len()));
+        ui.label(format!("b = {:.2} m, A = b²/S = {:.2}", span_loading.reference_span, span_loading.aspect_ratio));
+        ui.label("Trefftz plane: CDi = CL² / (πAe)");
+        ui.label(format!("CDi = {:.4}, e = {:.3} at CL = {:.2}", 
+            span_loading.induced_drag_coefficient(cl), span_loading.span_efficiency(cl), cl));
+        let shares: Vec<String> = span_loading
+            .surface_lift_fractions(cl)
+            .iter()
+            .map(|share| format!("{:.0}%", share * 100.0))
+            .collect();
+        if shares.len() > 1 {
+            let munk = &analysis.munk_interference;
+            let optimal: Vec<String> = munk.optimal_lift_shares.iter().map(|share| format!("{:.0}%", share * 100.0)).collect();
+            ui.label(format!("Lift share by surface: {}", shares.join(" / ")));
+            ui.separator();
+            ui.label("Munk: D = (1/πq) Σ σᵢⱼ LᵢLⱼ / (bᵢbⱼ), independent of stagger");
+            ui.label(format!("σ₁₂ = {:.3}, optimum split {} → e_min = {:.3}", 
+                munk.interference[0][1], optimal.join(" / "), munk.minimum_span_efficiency));
+        }
+        draw_span_loading(ui, span_loading, cl);
+    });
+    
+    ui.separator();
+    
     ui.group(|ui| {
         ui.label(RichText::new("Flight Conditions").strong());
         ui.label("Stall Speed: Vs = √(2W/ρSCLmax)");
         ui.label(format!("Vs = √(2×{:.0}/{:.3}×{:.1}×{:.2})", 
             analysis.weight_force, 
-            params.air_density, // Use consistent air density from params
+            analysis.atmosphere.density,
             analysis.wing_area, 
-            2.2));  // Use realistic CLmax
+            analysis.airfoil.cl_max));
         ui.label(format!("Vs = {:.1} m/s", analysis.stall_speed));
         ui.separator();
         
@@ -851,16 +1042,88 @@ fn draw_physics_equations(ui: &mut egui::Ui, analysis: &FlightAnalysis, params:
                 analysis.drag_force, analysis.effective_airspeed, analysis.power_to_overcome_drag));
             ui.separator();
             
+            ui.label("Parasite Drag: CdA = Σ Cf × FF × Q × S_wet + Σ (CdA)bluff");
+            for component in &analysis.parasite_drag.components {
+                if component.wetted_area > 0.0 {
+                    ui.label(format!("{}: S_wet {:.2} m², FF {:.2}, CdA {:.4} m²", 
+                        component.name, component.wetted_area, component.form_factor, component.drag_area));
+                } else {
+                    ui.label(format!("{}: CdA {:.4} m²", component.name, component.drag_area));
+                }
+            }
+            ui.label(format!("CD0 = {:.3}/{:.1} = {:.4}, P = {:.0} W", 
+                analysis.parasite_drag.drag_area, analysis.wing_area,
+                analysis.parasite_drag.drag_coefficient, analysis.power_for_parasite_drag));
+            ui.separator();
+            
+            ui.label("Propeller: T = B·c·s·½ρW²(CL cos φ - CD sin φ) = 2ρAF(V + v)v");
+            ui.label("CL = a(atan(p/2πr) - φ), φ = atan((V + v)/Ωr)");
+            ui.label("P_shaft = B·c·s·½ρW²(CL sin φ + CD cos φ)·Ωr");
+            ui.label(format!("η_prop = {:.3}, J = {:.2}, α₀.₇₅ = {:.1}°{}", 
+                analysis.propeller.efficiency, analysis.propeller.advance_ratio,
+                analysis.propeller.blade_angle_of_attack.to_degrees(),
+                if analysis.propeller.stalled { " (stalled)" } else { "" }));
+            ui.label(format!("P_shaft = {:.0} W", analysis.propeller.shaft_power));
+            ui.separator();
+            
+            if analysis.flapping.frequency > 0.0 {
+                let flapping = &analysis.flapping;
+                ui.label("Flapping: k = πfc/V, St = fA/V, C(k) = F + iG (Theodorsen)");
+                ui.label(format!("k = {:.3}, St = {:.2}, |C| = {:.2}, lag {:.1}°", 
+                    flapping.reduced_frequency, flapping.strouhal_number, flapping.lift_deficiency, flapping.phase_lag));
+                ui.label("T̄ = ⟨L sin φ (F² + G²)/F − D cos φ⟩, P = ⟨N ḣ⟩ + f I Φ²ω²");
+                ui.label(format!("L̄ = {:.0} N ({:.2}× fixed), T̄ = {:.1} N, η = {:.0}%", 
+                    flapping.lift, flapping.lift_factor, flapping.thrust, flapping.propulsive_efficiency * 100.0));
+                ui.label(format!("P = {:.0} W aero + {:.0} W inertial", flapping.aerodynamic_power, flapping.inertial_power));
+                ui.label("Spring: f_n = √(k/I)/2π, P_inertial = ⟨max(0, (Iθ̈ + kθ)θ̇) + cθ̇²⟩");
+                if flapping.mechanism.resonant_frequency > 0.0 {
+                    ui.label(format!("f_n = √({:.0}/{:.1})/2π = {:.2} Hz, f/f_n = {:.2}", 
+                        flapping.mechanism.spring_stiffness, flapping.mechanism.wing_inertia,
+                        flapping.mechanism.resonant_frequency, flapping.mechanism.frequency_ratio));
+                }
+                draw_flapping_cycle(ui, flapping);
+                ui.separator();
+            }
+            
             ui.label("Total Power Required:");
             ui.label(format!("P_total = {:.0} W", analysis.total_power_required));
         });
     }
     
+    ui.separator();
+    ui.group(|ui| {
+        let takeoff = &analysis.takeoff;
+        ui.label(RichText::new("Takeoff").strong());
+        ui.label("Ground Roll: m·dV/dt = T - D - μ(W cos θ - L) - W sin θ");
+        ui.label(format!("μ = {:.2} ({:?}), slope = {:.1}%", 
+            params.rolling_friction(), params.runway_surface, params.runway_slope * 100.0));
+        ui.label(format!("V_LO = 1.2 Vs = {:.1} m/s after {:.0} m, {:.1} s", 
+            takeoff.liftoff_speed, takeoff.ground_roll, takeoff.time_to_liftoff));
+        ui.label("Climb: sin γ = (T - D)/W");
+        ui.label(format!("γ = {:.1}°, over 15 m: {:.0} m", takeoff.climb_angle.to_degrees(), takeoff.obstacle_distance));
+    });
+    
+    ui.separator();
+    ui.group(|ui| {
+        let landing = &analysis.landing;
+        ui.label(RichText::new("Landing").strong());
+        ui.label("Approach: V_app = 1.3 Vs, tan γ = D/L (power off)");
+        ui.label(format!("V_app = {:.1} m/s, γ = {:.1}°, sink = {:.2} m/s", 
+            landing.approach_speed, landing.approach_angle.to_degrees(), landing.approach_sink_rate));
+        ui.label("Flare: R = V²/(g(n-1)), h_f = R(1 - cos γ)");
+        ui.label(format!("h_f = {:.2} m, V_td = 1.15 Vs = {:.1} m/s", landing.flare_height, landing.touchdown_speed));
+        ui.label("Ground Roll: m·dV/dt = -μ(W - L) - D");
+        ui.label(format!("μ = {:.2}, roll = {:.0} m", params.braking_friction, landing.ground_roll));
+        ui.label(format!("From 15 m: {:.0} + {:.0} = {:.0} m", 
+            landing.air_distance, landing.ground_roll, landing.landing_distance));
+    });
+    
     ui.separator();
     ui.group(|ui| {
         ui.label(RichText::new("Flight Phase Logic").strong());
         ui.label("• Speed < 1 m/s → ON GROUND");
         ui.label("• 1 m/s ≤ Speed < Vs×1.1 + High Power → TAKEOFF");
+        ui.label("• Vs ≤ Speed < Vs×1.3 + Low Power → LANDING");
         ui.label("• Speed ≥ Vs → IN FLIGHT");
         ui.separator();
         ui.label(format!("Current: {:?}", analysis.flight_phase));
@@ -869,89 +1132,6 @@ fn draw_physics_equations(ui: &mut egui::Ui, analysis: &FlightAnalysis, params:
     });
 }
 
-fn optimize_parameters(base_params: &FlightParams) -> FlightParams {
-    let mut best_params = base_params.clone();
-    let mut best_score = score_configuration(&best_params);
-    
-    let param_ranges = vec![
-        ("wing_span", 2.0, 8.0, 0.5),
-        ("wing_chord", 0.5, 3.0, 0.25),
-        ("motor_power", 0.0, 5000.0, 500.0),
-        ("forward_speed", 8.0, 20.0, 1.0),
-    ];
-    
-    for _ in 0..10 {
-        for (param_name, min_val, max_val, step) in &param_ranges {
-            let mut val = *min_val;
-            while val <= *max_val {
-                let mut test_params = best_params.clone();
-                
-                match param_name.as_ref() {
-                    "wing_span" => test_params.wing_span = val,
-                    "wing_chord" => test_params.wing_chord = val,
-                    "motor_power" => test_params.motor_power = val,
-                    "forward_speed" => test_params.forward_speed = val,
-                    _ => {}
-                }
-                
-                let score = score_configuration(&test_params);
-                if score > best_score {
-                    best_score = score;
-                    best_params = test_params;
-                }
-                
-                val += step;
-            }
-        }
-    }
-    
-    best_params
-}
-
-fn score_configuration(params: &FlightParams) -> f32 {
-    let analysis = calculate_comprehensive_flight_analysis(params);
-    
-    let mut score = 0.0;
-    
-    // Primary objective: sustained flight capability
-    if analysis.can_sustain_level_flight && analysis.structural.structural_feasible {
-        score += 1000.0;
-    } else if analysis.can_takeoff && analysis.structural.structural_feasible {
-        score += 300.0;  // Takeoff without sustain is less valuable
-    }
-    
-    // Efficiency metrics (minimize power requirements)
-    let power_efficiency = if analysis.total_power_required > 0.0 {
-        1000.0 / analysis.total_power_required  // Higher score for lower power
-    } else {
-        0.0
-    };
-    score += power_efficiency;
-    
-    // Mass penalty (lighter is better)
-    score -= analysis.total_mass * 5.0;
-    
-    // Wing loading penalty (lower wing loading is better for human flight)
-    score -= analysis.wing_loading * 0.1;
-    
-    // Stall speed penalty (lower stall speed is safer)
-    score -= analysis.stall_speed * 10.0;
-    
-    // Bonus for realistic motor endurance
-    if analysis.motor_flight_time > 10.0 && analysis.motor_flight_time < 60.0 {
-        score += 100.0;
-    }
-    
-    // Penalty for excessive takeoff distance
-    if analysis.takeoff_distance < 100.0 {
-        score += 100.0;
-    } else if analysis.takeoff_distance > 500.0 {
-        score -= (analysis.takeoff_distance - 500.0) * 0.1;
-    }
-    
-    score.max(0.0)  // Ensure non-negative scores
-}
-
 fn draw_parameter_heatmap(ui: &mut egui::Ui, params: &FlightParams) {
     ui.heading("Parameter Sensitivity Analysis");
     
@@ -969,8 +1149,7 @@ fn draw_parameter_heatmap(ui: &mut egui::Ui, params: &FlightParams) {
             let wing_chord = param2_range.0 + (param2_range.1 - param2_range.0) * (j as f32 / (param2_range.2 - 1) as f32);
             
             let mut test_params = params.clone();
-            test_params.wing_span = wing_span;
-            test_params.wing_chord = wing_chord;
+            test_params.resize_wing(wing_span, wing_chord);
             
             let analysis = calculate_comprehensive_flight_analysis(&test_params);
             let score = if analysis.can_sustain_level_flight { 
@@ -1027,6 +1206,89 @@ fn draw_parameter_heatmap(ui: &mut egui::Ui, params: &FlightParams) {
     ui.label("Green = Viable, Yellow = Marginal, Red = Not Viable");
 }
 
+fn draw_power_curve(ui: &mut egui::Ui, sweep: &SpeedSweep) {
+    ui.heading("Power Required vs Airspeed");
+    
+    let Some(first) = sweep.points.first() else {
+        return;
+    };
+    let last = sweep.points[sweep.points.len() - 1];
+    let (v_min, v_max) = (first.airspeed, last.airspeed);
+    
+    // Scale to the available-power lines so the interesting part of the curve stays visible
+    let p_max = sweep.pilot_burst_power
+        .max(sweep.sustained_power_available)
+        .max(sweep.min_power_required.min(5000.0))
+        * 1.3;
+    
+    let response = ui.allocate_response(EguiVec2::new(300.0, 160.0), egui::Sense::hover());
+    let painter = ui.painter_at(response.rect);
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, Stroke::new(1.0, Color32::from_rgb(120, 120, 120)), egui::StrokeKind::Inside);
+    
+    let to_x = |v: f32| rect.left() + (v - v_min) / (v_max - v_min) * rect.width();
+    let to_y = |p: f32| rect.bottom() - (p / p_max).clamp(0.0, 1.0) * rect.height();
+    
+    let hline = |power: f32, color: Color32, label: &str| {
+        if power > 0.0 {
+            let y = to_y(power);
+            painter.line_segment([egui::Pos2::new(rect.left(), y), egui::Pos2::new(rect.right(), y)], Stroke::new(1.0, color));
+            painter.text(egui::Pos2::new(rect.left() + 3.0, y - 2.0), egui::Align2::LEFT_BOTTOM,
+                format!("{} {:.0}W", label, power), egui::FontId::proportional(9.0), color);
+        }
+    };
+    hline(sweep.pilot_sustained_power, Color32::from_rgb(50, 150, 50), "Pilot sustained");
+    hline(sweep.pilot_burst_power, Color32::from_rgb(220, 140, 30), "Pilot burst");
+    hline(sweep.motor_shaft_power, Color32::from_rgb(50, 100, 200), "Motor");
+    
+    let vline = |speed: Option<f32>, color: Color32, label: &str| {
+        if let Some(speed) = speed {
+            let x = to_x(speed);
+            painter.line_segment([egui::Pos2::new(x, rect.top()), egui::Pos2::new(x, rect.bottom())], Stroke::new(1.0, color));
+            painter.text(egui::Pos2::new(x + 2.0, rect.top() + 2.0), egui::Align2::LEFT_TOP,
+                label, egui::FontId::proportional(9.0), color);
+        }
+    };
+    vline(Some(sweep.stall_speed), Color32::from_rgb(150, 150, 150), "Vs");
+    vline(sweep.v_min_power, Color32::from_rgb(200, 50, 50), "Vmp");
+    vline(sweep.v_max_lift_to_drag, Color32::from_rgb(120, 50, 160), "Vmd");
+    
+    let points: Vec<egui::Pos2> = sweep.points
+        .iter()
+        .filter(|point| !point.stalled && !point.propeller_limited)
+        .map(|point| egui::Pos2::new(to_x(point.airspeed), to_y(point.power_required)))
+        .collect();
+    for window in points.windows(2) {
+        painter.line_segment([window[0], window[1]], Stroke::new(2.0, Color32::from_rgb(200, 50, 50)));
+    }
+    
+    ui.label(format!("Airspeed {:.0}-{:.0} m/s, power 0-{:.0} W", v_min, v_max, p_max));
+    
+    ui.label(RichText::new("L/D vs Airspeed").strong());
+    let response = ui.allocate_response(EguiVec2::new(300.0, 80.0), egui::Sense::hover());
+    let painter = ui.painter_at(response.rect);
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, Stroke::new(1.0, Color32::from_rgb(120, 120, 120)), egui::StrokeKind::Inside);
+    let ld_max = sweep.max_lift_to_drag.max(1.0) * 1.1;
+    let points: Vec<egui::Pos2> = sweep.points
+        .iter()
+        .filter(|point| !point.stalled)
+        .map(|point| egui::Pos2::new(
+            rect.left() + (point.airspeed - v_min) / (v_max - v_min) * rect.width(),
+            rect.bottom() - (point.lift_to_drag / ld_max).clamp(0.0, 1.0) * rect.height(),
+        ))
+        .collect();
+    for window in points.windows(2) {
+        painter.line_segment([window[0], window[1]], Stroke::new(2.0, Color32::from_rgb(120, 50, 160)));
+    }
+    
+    let speed_text = |speed: Option<f32>| speed.map_or("-".to_string(), |v| format!("{:.1} m/s", v));
+    ui.label(format!("V_min_power: {} ({:.0} W)", speed_text(sweep.v_min_power), sweep.min_power_required));
+    ui.label(format!("V_max_L/D: {} (L/D {:.1})", speed_text(sweep.v_max_lift_to_drag), sweep.max_lift_to_drag));
+    let v_max_text = if sweep.v_max_above_sweep { format!("> {:.0} m/s (sweep limit)", v_max) } else { speed_text(sweep.v_max) };
+    ui.label(format!("V_max (sustained): {}", v_max_text));
+}
+
 fn draw_real_time_plots(ui: &mut egui::Ui, history: &HistoryData) {
     ui.heading("Performance History");
     
@@ -1107,16 +1369,44 @@ fn draw_real_time_plots(ui: &mut egui::Ui, history: &HistoryData) {
 
 #[macroquad::main("Advanced Human Flight Engineering System")]
 async fn main() {
+    let materials = MaterialLibrary::load();
     let mut state = SimulationState {
         params: FlightParams::default(),
         analysis: calculate_comprehensive_flight_analysis(&FlightParams::default()),
         history: HistoryData::new(),
-        optimization_running: false,
         optimization_result: None,
         camera_rotation: 0.0,
         time: 0.0,
-        selected_preset: FlightPreset::Default,
+        selected_preset: Some("Default".to_string()),
+        presets: PresetLibrary::load(&materials),
+        new_preset: NewPresetForm {
+            name: String::new(),
+            description: String::new(),
+            status: None,
+        },
+        materials,
+        new_material: NewMaterialForm {
+            material: Material {
+                name: String::new(),
+                description: String::new(),
+                ..Material::named("Balsa").expect("built-in material")
+            },
+            status: None,
+        },
         active_tab: UITab::Configuration,
+        design_file: DesignFile::new(),
+        airfoil_import: AirfoilImportForm {
+            coordinates_path: String::new(),
+            polar_paths: String::new(),
+            status: None,
+        },
+        naca: NacaForm {
+            designation: String::new(),
+            status: None,
+        },
+        mission: MissionSimulator::new(&FlightParams::default(), &MissionProfile::default()),
+        mission_profile: MissionProfile::default(),
+        mission_time_scale: 1.0,
     };
     
     loop {
@@ -1124,6 +1414,7 @@ async fn main() {
         
         state.time += get_frame_time();
         state.camera_rotation += get_frame_time() * 0.3;
+        state.mission.advance(get_frame_time() * state.mission_time_scale);
         
         state.history.update(&state.analysis, state.time);
         
@@ -1158,6 +1449,10 @@ async fn main() {
                     
                     ui.separator();
                     
+                    draw_file_controls(ui, &mut state);
+                    
+                    ui.separator();
+                    
                     ui.horizontal(|ui| {
                         ui.selectable_value(&mut state.active_tab, UITab::Configuration, "Config");
                         ui.selectable_value(&mut state.active_tab, UITab::Analysis, "Analysis");
@@ -1170,46 +1465,7 @@ async fn main() {
                     egui::ScrollArea::vertical().show(ui, |ui| {
                         match state.active_tab {
                             UITab::Configuration => {
-                                ui.heading("Flight Scenarios");
-                                ui.label("Human-powered flight requires extreme efficiency:");
-                                
-                                if ui.button("🪶 Pure Human Power").clicked() {
-                                    state.params = FlightParams::from_preset(FlightPreset::UltralightGlider);
-                                    state.selected_preset = FlightPreset::UltralightGlider;
-                                }
-                                ui.label("• Like Gossamer Albatross • 12m wings • Elite athlete");
-                                ui.separator();
-                                
-                                if ui.button("🚀 Motor-Assisted Takeoff").clicked() {
-                                    state.params = FlightParams::from_preset(FlightPreset::PoweredTakeoff);
-                                    state.selected_preset = FlightPreset::PoweredTakeoff;
-                                }
-                                ui.label("• 8kW motor for takeoff • Large wings • Hybrid power");
-                                ui.separator();
-                                
-                                if ui.button("✈️ Long-Distance Flight").clicked() {
-                                    state.params = FlightParams::from_preset(FlightPreset::SustainedFlight);
-                                    state.selected_preset = FlightPreset::SustainedFlight;
-                                }
-                                ui.label("• 15m wings • Continuous motor • Ultra-efficient");
-                                ui.separator();
-                                
-                                ui.horizontal(|ui| {
-                                    if ui.button("Efficient").clicked() {
-                                        state.params = FlightParams::from_preset(FlightPreset::MaxEfficiency);
-                                        state.selected_preset = FlightPreset::MaxEfficiency;
-                                    }
-                                    if ui.button("Minimal").clicked() {
-                                        state.params = FlightParams::from_preset(FlightPreset::MinimalWeight);
-                                        state.selected_preset = FlightPreset::MinimalWeight;
-                                    }
-                                    if ui.button("Racing").clicked() {
-                                        state.params = FlightParams::from_preset(FlightPreset::RacingConfig);
-                                        state.selected_preset = FlightPreset::RacingConfig;
-                                    }
-                                });
-                                
-                                ui.label(format!("Current: {:?}", state.selected_preset));
+                                draw_preset_list(ui, &mut state);
                                 ui.separator();
                                 
                                 ui.heading("Pilot & Power");
@@ -1217,13 +1473,30 @@ async fn main() {
                                     .text("Pilot Mass")
                                     .suffix(" kg"));
                                 
-                                ui.add(egui::Slider::new(&mut state.params.pilot_power_sustained, 75.0..=500.0)
-                                    .text("Sustained Power")
-                                    .suffix(" W"));
+                                ui.horizontal(|ui| {
+                                    ui.label("Athlete:");
+                                    ui.selectable_value(&mut state.params.pilot_profile, PilotProfile::Custom, "Custom");
+                                    ui.selectable_value(&mut state.params.pilot_profile, PilotProfile::Recreational, "Recreational");
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.selectable_value(&mut state.params.pilot_profile, PilotProfile::TrainedCyclist, "Trained Cyclist");
+                                    ui.selectable_value(&mut state.params.pilot_profile, PilotProfile::Elite, "Elite");
+                                });
                                 
-                                ui.add(egui::Slider::new(&mut state.params.pilot_power_burst, 200.0..=1500.0)
-                                    .text("Burst Power")
-                                    .suffix(" W"));
+                                if state.params.pilot_profile == PilotProfile::Custom {
+                                    ui.add(egui::Slider::new(&mut state.params.pilot_power_sustained, 75.0..=500.0)
+                                        .text("Sustained Power")
+                                        .suffix(" W"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.pilot_power_burst, 200.0..=1500.0)
+                                        .text("Burst Power")
+                                        .suffix(" W"));
+                                }
+                                
+                                ui.label(format!("CP: {:.0} W | W': {:.1} kJ | 1-min: {:.0} W", 
+                                    state.analysis.pilot.critical_power, 
+                                    state.analysis.pilot.anaerobic_capacity / 1000.0, 
+                                    state.analysis.pilot.burst_power));
                                 
                                 ui.add(egui::Slider::new(&mut state.params.motor_power, 0.0..=5000.0)
                                     .text("Motor Power")
@@ -1233,50 +1506,234 @@ async fn main() {
                                     .text("Battery")
                                     .suffix(" Wh"));
                                 
-                                ui.separator();
-                                
-                                ui.heading("Wing Configuration");
                                 ui.horizontal(|ui| {
-                                    ui.label("Wings:");
-                                    if ui.button(format!("{}", state.params.wing_count)).clicked() {
-                                        state.params.wing_count = if state.params.wing_count == 2 { 4 } else { 2 };
-                                    }
+                                    ui.label("Chemistry:");
+                                    ui.selectable_value(&mut state.params.battery_chemistry, BatteryChemistry::LiPo, "LiPo");
+                                    ui.selectable_value(&mut state.params.battery_chemistry, BatteryChemistry::LiIon, "Li-ion");
+                                    ui.selectable_value(&mut state.params.battery_chemistry, BatteryChemistry::LiFePO4, "LiFePO4");
                                 });
                                 
-                                ui.add(egui::Slider::new(&mut state.params.wing_span, 1.5..=8.0)
-                                    .text("Wing Span")
+                                ui.add(egui::Slider::new(&mut state.params.battery_series_cells, 1..=24)
+                                    .text("Series Cells"));
+                                
+                                ui.add(egui::Slider::new(&mut state.params.battery_depth_of_discharge, 0.5..=1.0)
+                                    .text("Depth of Discharge"));
+                                
+                                if state.analysis.battery.capacity_ah > 0.0 {
+                                    ui.label(format!("Pack: {:.1} kg, {:.1} V, {:.1} Ah", 
+                                        state.analysis.battery.mass, state.analysis.battery.nominal_voltage, state.analysis.battery.capacity_ah));
+                                } else {
+                                    ui.label("Pack: no battery");
+                                }
+                                
+                                ui.separator();
+                                
+                                ui.heading("Propeller");
+                                ui.add(egui::Slider::new(&mut state.params.propeller_diameter, 0.5..=4.0)
+                                    .text("Diameter")
+                                    .suffix(" m"));
+                                
+                                ui.add(egui::Slider::new(&mut state.params.propeller_pitch, 0.5..=6.0)
+                                    .text("Pitch")
                                     .suffix(" m"));
                                 
-                                ui.add(egui::Slider::new(&mut state.params.wing_chord, 0.3..=3.0)
-                                    .text("Wing Chord")
+                                ui.add(egui::Slider::new(&mut state.params.propeller_blades, 2..=6)
+                                    .text("Blades"));
+                                
+                                ui.add(egui::Slider::new(&mut state.params.propeller_blade_chord, 0.03..=0.4)
+                                    .text("Blade Chord")
                                     .suffix(" m"));
                                 
+                                ui.add(egui::Slider::new(&mut state.params.propeller_rpm, 50.0..=1500.0)
+                                    .text("Full RPM"));
+                                
+                                ui.add(egui::Slider::new(&mut state.params.drivetrain_efficiency, 0.7..=1.0)
+                                    .text("Drivetrain Eff"));
+                                
+                                ui.label(format!("Propeller Efficiency: {:.0}% (J = {:.2} at {:.0} rpm)", 
+                                    state.analysis.propeller.efficiency * 100.0, state.analysis.propeller.advance_ratio,
+                                    state.analysis.propeller.rpm));
+                                if state.analysis.propeller.limited {
+                                    ui.colored_label(Color32::from_rgb(220, 80, 60), "The propeller cannot deliver the thrust needed at full RPM");
+                                } else if state.analysis.propeller.stalled {
+                                    ui.colored_label(Color32::from_rgb(220, 160, 60), "Blades stalled: the pitch is too coarse for this airspeed");
+                                }
+                                
+                                ui.separator();
+                                
+                                ui.heading("Wing Configuration");
+                                ui.add(egui::Slider::new(&mut state.params.wing_count, 2..=8)
+                                    .step_by(2.0)
+                                    .text("Wings"));
+                                
+                                if state.params.wing_panels.is_empty() {
+                                    ui.add(egui::Slider::new(&mut state.params.wing_span, 1.5..=8.0)
+                                        .text("Wing Span")
+                                        .suffix(" m"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_chord, 0.3..=3.0)
+                                        .text("Wing Chord")
+                                        .suffix(" m"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_taper_ratio, 0.2..=1.0)
+                                        .text("Taper Ratio"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_twist, -5.0..=8.0)
+                                        .text("Washout")
+                                        .suffix("°"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_dihedral, -5.0..=15.0)
+                                        .text("Dihedral")
+                                        .suffix("°"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_sweep, -10.0..=40.0)
+                                        .text("Sweep")
+                                        .suffix("°"));
+                                }
+                                
+                                draw_wing_panels(ui, &mut state.params);
+                                draw_planform(ui, &state.params.planform());
+                                
                                 ui.add(egui::Slider::new(&mut state.params.wing_thickness_ratio, 0.05..=0.20)
                                     .text("Thickness")
                                     .suffix(""));
                                 
+                                if state.params.wing_count > 2 {
+                                    ui.horizontal(|ui| {
+                                        ui.label("Layout:");
+                                        ui.selectable_value(&mut state.params.wing_layout, WingLayout::Tandem, "Tandem");
+                                        ui.selectable_value(&mut state.params.wing_layout, WingLayout::Biplane, "Biplane");
+                                        ui.selectable_value(&mut state.params.wing_layout, WingLayout::Canard, "Canard");
+                                    });
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_gap, 0.0..=2.0)
+                                        .text("Gap")
+                                        .suffix(" m"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_stagger, -4.0..=4.0)
+                                        .text("Stagger")
+                                        .suffix(" m"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.wing_decalage, -4.0..=4.0)
+                                        .text("Decalage")
+                                        .suffix("°"));
+                                    
+                                    if state.params.wing_layout == WingLayout::Canard {
+                                        ui.add(egui::Slider::new(&mut state.params.canard_scale, 0.2..=1.0)
+                                            .text("Canard Size"));
+                                    }
+                                }
+                                
+                                let previous_airfoil = state.params.airfoil.clone();
+                                ui.horizontal(|ui| {
+                                    ui.label("Airfoil:");
+                                    ui.selectable_value(&mut state.params.airfoil, AirfoilSection::Custom, "Custom");
+                                    for (section, label) in [(AirfoilSection::Dae11, "DAE-11"), (AirfoilSection::Fx76Mp140, "FX 76-MP-140")] {
+                                        draw_tabulated_airfoil(ui, &mut state.params.airfoil, section, label);
+                                    }
+                                });
                                 ui.horizontal(|ui| {
-                                    ui.label("Wing:");
-                                    ui.selectable_value(&mut state.params.wing_material, WingMaterial::Fabric, "Fabric");
-                                    ui.selectable_value(&mut state.params.wing_material, WingMaterial::Carbon, "Carbon");
+                                    for (section, label) in [(AirfoilSection::Naca0012, "NACA 0012"), (AirfoilSection::Naca4412, "NACA 4412")] {
+                                        draw_tabulated_airfoil(ui, &mut state.params.airfoil, section, label);
+                                    }
+                                    if let Some(imported) = &state.params.imported_airfoil {
+                                        let name = imported.name.clone();
+                                        ui.selectable_value(&mut state.params.airfoil, AirfoilSection::Imported, name);
+                                    }
                                 });
+                                if let AirfoilSection::Naca(designation) = &state.params.airfoil {
+                                    let label = format!("NACA {}", designation);
+                                    let current = state.params.airfoil.clone();
+                                    ui.selectable_value(&mut state.params.airfoil, current, label);
+                                }
+                                if state.params.airfoil != previous_airfoil {
+                                    // A tabulated or generated section brings its own thickness
+                                    if let Some(thickness) = section_thickness_ratio(&state.params) {
+                                        state.params.wing_thickness_ratio = thickness;
+                                    }
+                                }
+                                
+                                draw_naca_generator(ui, &mut state);
+                                let coordinates = section_coordinates(&state.params);
+                                if coordinates.len() > 2 {
+                                    draw_airfoil_shape(ui, &coordinates);
+                                }
+                                
+                                draw_airfoil_import(ui, &mut state);
+                                
+                                if state.params.airfoil == AirfoilSection::Custom {
+                                    ui.add(egui::Slider::new(&mut state.params.airfoil_cl_max, 0.8..=2.2)
+                                        .text("CLmax"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.airfoil_cd_min, 0.004..=0.03)
+                                        .text("CDmin"));
+                                    
+                                    ui.add(egui::Slider::new(&mut state.params.airfoil_zero_lift_angle, -8.0..=0.0)
+                                        .text("Zero-lift α")
+                                        .suffix("°"));
+                                } else {
+                                    ui.label(format!("CLmax {:.2}, CDmin {:.4} at Re {:.0}", 
+                                        state.analysis.airfoil.cl_max, state.analysis.airfoil.cd_min, state.analysis.airfoil.reynolds));
+                                }
+                                
+                                draw_material_picker(ui, "Wing:", &mut state.params.wing_material, &state.materials);
+                                draw_material_picker(ui, "Spar:", &mut state.params.spar_material, &state.materials);
+                                draw_new_material(ui, &mut state.materials, &mut state.new_material);
                                 ui.horizontal(|ui| {
-                                    ui.selectable_value(&mut state.params.wing_material, WingMaterial::Wood, "Wood");
-                                    ui.selectable_value(&mut state.params.wing_material, WingMaterial::Aluminum, "Aluminum");
+                                    ui.label("Section:");
+                                    ui.selectable_value(&mut state.params.spar_section, SparSection::Rectangular, "Rectangular");
+                                    ui.selectable_value(&mut state.params.spar_section, SparSection::Tube, "Tube");
                                 });
+                                if state.params.spar_section == SparSection::Tube && state.params.spar_layup.is_empty() {
+                                    ui.add(egui::Slider::new(&mut state.params.spar_wall_thickness, 0.3..=6.0)
+                                        .text("Wall Thickness")
+                                        .suffix(" mm"));
+                                }
+                                draw_spar_layup(ui, &mut state.params);
+                                
+                                ui.label(format!("Wing Area: {:.1} m²", state.params.wing_area()));
+                                ui.label(format!("Mean Aerodynamic Chord: {:.2} m", state.params.planform().mean_aerodynamic_chord()));
+                                ui.label(format!("Aspect Ratio: {:.2} (span efficiency {:.2})", 
+                                    state.params.aspect_ratio(), state.analysis.span_efficiency));
+                                
+                                ui.separator();
                                 
+                                ui.heading("Airframe");
+                                ui.horizontal(|ui| {
+                                    ui.label("Pilot:");
+                                    ui.selectable_value(&mut state.params.pilot_position, PilotPosition::Upright, "Upright");
+                                    ui.selectable_value(&mut state.params.pilot_position, PilotPosition::Recumbent, "Recumbent");
+                                    ui.selectable_value(&mut state.params.pilot_position, PilotPosition::Prone, "Prone");
+                                });
                                 ui.horizontal(|ui| {
-                                    ui.label("Spar:");
-                                    ui.selectable_value(&mut state.params.spar_material, SparMaterial::Carbon, "Carbon");
-                                    ui.selectable_value(&mut state.params.spar_material, SparMaterial::Aluminum, "Aluminum");
+                                    ui.label("Fairing:");
+                                    ui.selectable_value(&mut state.params.fairing, FairingType::Open, "Open");
+                                    ui.selectable_value(&mut state.params.fairing, FairingType::NoseCone, "Nose Cone");
+                                    ui.selectable_value(&mut state.params.fairing, FairingType::Enclosed, "Enclosed");
                                 });
                                 ui.horizontal(|ui| {
-                                    ui.selectable_value(&mut state.params.spar_material, SparMaterial::Wood, "Wood");
-                                    ui.selectable_value(&mut state.params.spar_material, SparMaterial::Steel, "Steel");
+                                    ui.label("Gear:");
+                                    ui.selectable_value(&mut state.params.landing_gear, LandingGear::Fixed, "Fixed");
+                                    ui.selectable_value(&mut state.params.landing_gear, LandingGear::Faired, "Faired");
+                                    ui.selectable_value(&mut state.params.landing_gear, LandingGear::Retracted, "Retracted");
                                 });
+                                ui.add(egui::Slider::new(&mut state.params.wheel_count, 1..=3)
+                                    .text("Wheels"));
                                 
-                                ui.label(format!("Wing Area: {:.1} m²", state.params.wing_area()));
-                                ui.label(format!("Aspect Ratio: {:.2}", state.params.aspect_ratio()));
+                                ui.add(egui::Slider::new(&mut state.params.tail_area, 0.0..=6.0)
+                                    .text("Tail Area")
+                                    .suffix(" m²"));
+                                
+                                ui.add(egui::Slider::new(&mut state.params.tail_boom_length, 0.0..=8.0)
+                                    .text("Tail Boom")
+                                    .suffix(" m"));
+                                
+                                ui.add(egui::Slider::new(&mut state.params.bracing_wire_length, 0.0..=100.0)
+                                    .text("Bracing Wires")
+                                    .suffix(" m"));
+                                
+                                ui.label(format!("Airframe Drag Area: {:.3} m² (CD0 {:.4})", 
+                                    state.analysis.parasite_drag.drag_area, state.analysis.parasite_drag.drag_coefficient));
                                 
                                 ui.separator();
                                 
@@ -1284,6 +1741,7 @@ async fn main() {
                                 ui.add(egui::Slider::new(&mut state.params.forward_speed, 3.0..=35.0)
                                     .text("Forward Speed")
                                     .suffix(" m/s"));
+                                ui.checkbox(&mut state.params.on_approach, "On Approach (power-off glide)");
                                 
                                 ui.label(format!("Stall Speed: {:.1} m/s", state.analysis.stall_speed));
                                 
@@ -1295,9 +1753,115 @@ async fn main() {
                                     .text("Flapping Amp")
                                     .suffix("°"));
                                 
+                                ui.collapsing("Stroke Kinematics", |ui| {
+                                    ui.add(egui::Slider::new(&mut state.params.flapping_upstroke_ratio, 0.5..=2.0)
+                                        .text("Upstroke/Downstroke"));
+                                    ui.add(egui::Slider::new(&mut state.params.flapping_pitch_amplitude, 0.0..=40.0)
+                                        .text("Feathering")
+                                        .suffix("°"));
+                                    ui.add(egui::Slider::new(&mut state.params.flapping_pitch_lag, -90.0..=90.0)
+                                        .text("Feathering Lag")
+                                        .suffix("°"));
+                                    ui.add(egui::Slider::new(&mut state.params.flapping_stroke_plane, -30.0..=30.0)
+                                        .text("Stroke Plane")
+                                        .suffix("°"));
+                                    if state.params.wing_count > 2 {
+                                        ui.add(egui::Slider::new(&mut state.params.flapping_phase_offset, 0.0..=360.0)
+                                            .text("Pair Phase Offset")
+                                            .suffix("°"));
+                                    }
+                                });
+                                
+                                ui.collapsing("Spring Mechanism", |ui| {
+                                    ui.add(egui::Slider::new(&mut state.params.flapping_spring_stiffness, 0.0..=200000.0)
+                                        .logarithmic(true)
+                                        .text("Spring Stiffness")
+                                        .suffix(" N·m/rad"));
+                                    ui.add(egui::Slider::new(&mut state.params.flapping_damping_ratio, 0.0..=0.2)
+                                        .text("Damping Ratio"));
+                                    let mechanism = &state.analysis.flapping.mechanism;
+                                    if ui.button("Tune to Resonance").clicked() {
+                                        let omega = 2.0 * std::f32::consts::PI * state.params.flapping_frequency;
+                                        state.params.flapping_spring_stiffness = mechanism.wing_inertia * omega.powi(2);
+                                    }
+                                    ui.label(format!("Wing Inertia: {:.1} kg·m²", mechanism.wing_inertia));
+                                    if mechanism.resonant_frequency > 0.0 {
+                                        ui.label(format!("Resonance: {:.2} Hz ({:+.0}% off)", 
+                                            mechanism.resonant_frequency, (mechanism.frequency_ratio - 1.0) * 100.0));
+                                    }
+                                    ui.label(format!("Inertial Power: {:.0} W of {:.0} W unsprung", 
+                                        state.analysis.flapping.inertial_power, mechanism.unsprung_power));
+                                });
+                                
                                 ui.add(egui::Slider::new(&mut state.params.wind_speed, -10.0..=10.0)
                                     .text("Wind Speed")
                                     .suffix(" m/s"));
+                                
+                                ui.separator();
+                                
+                                ui.heading("Runway");
+                                ui.horizontal(|ui| {
+                                    ui.label("Surface:");
+                                    ui.selectable_value(&mut state.params.runway_surface, RunwaySurface::Paved, "Paved");
+                                    ui.selectable_value(&mut state.params.runway_surface, RunwaySurface::Dirt, "Dirt");
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.selectable_value(&mut state.params.runway_surface, RunwaySurface::ShortGrass, "Short Grass");
+                                    ui.selectable_value(&mut state.params.runway_surface, RunwaySurface::LongGrass, "Long Grass");
+                                });
+                                
+                                let mut slope_percent = state.params.runway_slope * 100.0;
+                                if ui.add(egui::Slider::new(&mut slope_percent, -5.0..=5.0)
+                                    .text("Slope (uphill +)")
+                                    .suffix(" %")).changed() {
+                                    state.params.runway_slope = slope_percent / 100.0;
+                                }
+                                
+                                ui.add(egui::Slider::new(&mut state.params.braking_friction, 0.02..=0.5)
+                                    .text("Braking Friction"));
+                                
+                                ui.separator();
+                                
+                                ui.heading("Atmosphere");
+                                ui.add(egui::Slider::new(&mut state.params.altitude, 0.0..=5000.0)
+                                    .text("Altitude")
+                                    .suffix(" m"));
+                                
+                                ui.add(egui::Slider::new(&mut state.params.temperature_offset, -30.0..=30.0)
+                                    .text("ISA Temp Offset")
+                                    .suffix(" K"));
+                                
+                                let mut humidity_percent = state.params.relative_humidity * 100.0;
+                                if ui.add(egui::Slider::new(&mut humidity_percent, 0.0..=100.0)
+                                    .text("Humidity")
+                                    .suffix(" %")).changed() {
+                                    state.params.relative_humidity = humidity_percent / 100.0;
+                                }
+                                
+                                ui.label(format!("Air Density: {:.3} kg/m³ ({:.1} °C)", 
+                                    state.analysis.atmosphere.density, state.analysis.atmosphere.temperature_celsius()));
+                                
+                                ui.separator();
+                                
+                                ui.heading("Mission");
+                                ui.add(egui::Slider::new(&mut state.mission_profile.cruise_altitude, 2.0..=50.0)
+                                    .text("Cruise Altitude")
+                                    .suffix(" m"));
+                                
+                                ui.add(egui::Slider::new(&mut state.mission_profile.cruise_distance, 100.0..=10000.0)
+                                    .text("Cruise Distance")
+                                    .suffix(" m"));
+                                
+                                ui.add(egui::Slider::new(&mut state.mission_time_scale, 0.5..=10.0)
+                                    .text("Time Scale")
+                                    .suffix("×"));
+                                
+                                ui.horizontal(|ui| {
+                                    if ui.button("🔄 Restart Mission").clicked() {
+                                        state.mission = MissionSimulator::new(&state.params, &state.mission_profile);
+                                    }
+                                    ui.label(format!("{:?} at {:.0} s", state.mission.state().phase, state.mission.state().time));
+                                });
                             },
                             
                             UITab::Analysis => {
@@ -1305,12 +1869,16 @@ async fn main() {
                                 ui.label(format!("Total Mass: {:.0} kg", state.analysis.total_mass));
                                 ui.label(format!("Wing Loading: {:.1} N/m²", state.analysis.wing_loading));
                                 ui.label(format!("Reynolds: {:.0}", state.analysis.reynolds_number));
+                                ui.label(format!("Air Density: {:.3} kg/m³", state.analysis.atmosphere.density));
                                 ui.separator();
                                 
                                 ui.heading("Current Flight State");
                                 ui.label(format!("Phase: {:?}", state.analysis.flight_phase));
                                 ui.label(format!("Speed: {:.1} m/s", state.analysis.effective_airspeed));
                                 if state.analysis.flight_phase != FlightPhase::OnGround {
+                                    ui.label(format!("Angle of Attack: {:.1}°", state.analysis.angle_of_attack));
+                                    let margin_color = if state.analysis.stall_margin < 2.0 { Color32::from_rgb(200, 50, 50) } else { Color32::from_rgb(50, 150, 50) };
+                                    ui.colored_label(margin_color, format!("Stall Margin: {:.1}°", state.analysis.stall_margin));
                                     ui.label(format!("Lift Coeff: {:.3}", state.analysis.current_lift_coefficient));
                                     ui.label(format!("Drag Coeff: {:.4}", state.analysis.current_drag_coefficient));
                                     ui.label(format!("L/W Ratio: {:.2}", state.analysis.lift_force / state.analysis.weight_force));
@@ -1322,12 +1890,25 @@ async fn main() {
                                 ui.label(format!("Total Power: {:.0} W", state.analysis.total_power_required));
                                 if state.analysis.flight_phase != FlightPhase::OnGround {
                                     let total = state.analysis.total_power_required.max(1.0);
-                                    ui.label(format!("Drag: {:.0}W ({:.0}%)", 
-                                        state.analysis.power_to_overcome_drag,
-                                        (state.analysis.power_to_overcome_drag / total) * 100.0));
+                                    let wing_drag_power = state.analysis.power_to_overcome_drag - state.analysis.power_for_parasite_drag;
+                                    ui.label(format!("Wing Drag: {:.0}W ({:.0}%)", 
+                                        wing_drag_power,
+                                        (wing_drag_power / total) * 100.0));
+                                    ui.label(format!("Parasite Drag: {:.0}W ({:.0}%)", 
+                                        state.analysis.power_for_parasite_drag,
+                                        (state.analysis.power_for_parasite_drag / total) * 100.0));
+                                    let propeller_loss = state.analysis.propeller.shaft_power - state.analysis.propeller.thrust * state.analysis.effective_airspeed;
+                                    ui.label(format!("Propeller Loss: {:.0}W ({:.0}%)", 
+                                        propeller_loss,
+                                        (propeller_loss / total) * 100.0));
                                     ui.label(format!("Flapping: {:.0}W ({:.0}%)", 
                                         state.analysis.power_for_flapping,
                                         (state.analysis.power_for_flapping / total) * 100.0));
+                                    if state.analysis.flapping.frequency > 0.0 {
+                                        ui.label(format!("  Flapping Thrust: {:.1} N at η {:.0}%", 
+                                            state.analysis.flapping.thrust,
+                                            state.analysis.flapping.propulsive_efficiency * 100.0));
+                                    }
                                 }
                                 
                                 ui.separator();
@@ -1346,8 +1927,15 @@ async fn main() {
                                 });
                                 
                                 ui.label(format!("Wing Mass: {:.1} kg", state.analysis.structural.wing_mass));
+                                ui.label(format!("Spar Mass: {:.1} kg (EI {:.0} N·m²)", state.analysis.structural.spar_mass,
+                                    state.analysis.structural.spar.modulus * state.analysis.structural.spar.second_moment));
                                 ui.label(format!("Total Mass: {:.1} kg", state.analysis.structural.total_structural_mass));
+                                ui.label(format!("Battery Mass: {:.1} kg", state.analysis.structural.battery_mass));
                                 ui.label(format!("Load Factor: {:.2} g", state.analysis.structural.max_load_factor));
+                                ui.label(format!("Strength Margin: {:.2} (root stress {:.0} MPa)",
+                                    state.analysis.structural.strength_margin, state.analysis.structural.root_bending_stress / 1e6));
+                                ui.label(format!("Fatigue Margin: {:.2}", state.analysis.structural.fatigue_margin));
+                                ui.label(format!("Material Cost: ${:.0}", state.analysis.structural.material_cost));
                                 
                                 ui.separator();
                                 
@@ -1368,6 +1956,72 @@ async fn main() {
                                     );
                                 });
                                 
+                                ui.separator();
+                                
+                                ui.heading("Pilot Endurance");
+                                let pilot = &state.analysis.pilot;
+                                if state.analysis.flight_phase != FlightPhase::OnGround {
+                                    ui.label(format!("Pedal Power: {:.0} W ({:.0}% of CP)", 
+                                        pilot.power_required, pilot.power_required / pilot.critical_power.max(1.0) * 100.0));
+                                    if pilot.max_flight_duration.is_finite() {
+                                        ui.label(format!("Max Flight Duration: {:.1} min", pilot.max_flight_duration));
+                                    } else {
+                                        ui.label("Max Flight Duration: over 24 h");
+                                    }
+                                } else {
+                                    ui.label("Not flying");
+                                }
+                                
+                                ui.separator();
+                                
+                                if state.params.motor_power > 0.0 && state.params.battery_capacity > 0.0 {
+                                    ui.heading("Battery");
+                                    let battery = &state.analysis.battery;
+                                    ui.label(format!("Cruise Draw: {:.1} A ({:.1}C)", battery.load.current, battery.load.c_rate));
+                                    ui.label(format!("Terminal Voltage: {:.1} V ({:.2} V sag)", battery.load.terminal_voltage, battery.load.voltage_sag));
+                                    ui.label(format!("Usable Energy: {:.0} Wh", battery.usable_energy));
+                                    if battery.endurance.is_finite() {
+                                        ui.label(format!("Endurance: {:.1} min", battery.endurance));
+                                    } else {
+                                        ui.label("Endurance: motor idle at cruise");
+                                    }
+                                    
+                                    ui.separator();
+                                } else if state.params.motor_power > 0.0 {
+                                    ui.heading("Battery");
+                                    ui.label("No battery: the motor has nothing to draw on");
+                                    
+                                    ui.separator();
+                                }
+                                
+                                ui.heading("Takeoff");
+                                let takeoff = &state.analysis.takeoff;
+                                if takeoff.ground_roll.is_finite() {
+                                    ui.label(format!("Ground Roll: {:.0} m in {:.1} s", takeoff.ground_roll, takeoff.time_to_liftoff));
+                                    ui.label(format!("Liftoff: {:.1} m/s, climb {:.1}°", takeoff.liftoff_speed, takeoff.climb_angle.to_degrees()));
+                                    if takeoff.obstacle_distance.is_finite() {
+                                        ui.label(format!("Distance over 15 m: {:.0} m", takeoff.obstacle_distance));
+                                    } else {
+                                        ui.colored_label(Color32::from_rgb(200, 150, 50), "Cannot climb after liftoff");
+                                    }
+                                } else {
+                                    ui.colored_label(Color32::from_rgb(200, 50, 50), "Cannot reach liftoff speed");
+                                }
+                                
+                                ui.separator();
+                                
+                                ui.heading("Landing");
+                                let landing = &state.analysis.landing;
+                                ui.label(format!("Approach: {:.1} m/s, {:.1}° glide, {:.2} m/s sink", 
+                                    landing.approach_speed, landing.approach_angle.to_degrees(), landing.approach_sink_rate));
+                                ui.label(format!("Flare Height: {:.2} m", landing.flare_height));
+                                ui.label(format!("Touchdown: {:.1} m/s", landing.touchdown_speed));
+                                ui.label(format!("Ground Roll: {:.0} m", landing.ground_roll));
+                                ui.label(format!("Landing Distance (15 m): {:.0} m", landing.landing_distance));
+                                
+                                ui.separator();
+                                draw_power_curve(ui, &calculate_speed_sweep(&state.params));
+                                
                                 ui.separator();
                                 draw_real_time_plots(ui, &state.history);
                             },
@@ -1380,9 +2034,7 @@ async fn main() {
                                 ui.heading("Parameter Optimization");
                                 
                                 if ui.button("🔍 Find Optimal Configuration").clicked() {
-                                    state.optimization_running = true;
                                     state.optimization_result = Some(optimize_parameters(&state.params));
-                                    state.optimization_running = false;
                                 }
                                 
                                 if let Some(ref optimal) = state.optimization_result {
@@ -1395,7 +2047,7 @@ async fn main() {
                                     
                                     if ui.button("✅ Apply Optimal Parameters").clicked() {
                                         state.params = optimal.clone();
-                                        state.selected_preset = FlightPreset::Default;
+                                        state.selected_preset = None;
                                     }
                                 }
                                 
@@ -1407,6 +2059,11 @@ async fn main() {
                     });
                     
                     state.analysis = calculate_comprehensive_flight_analysis(&state.params);
+                    
+                    // Any design or mission change restarts the flight from the runway
+                    if state.mission.params() != &state.params || state.mission.profile() != &state.mission_profile {
+                        state.mission = MissionSimulator::new(&state.params, &state.mission_profile);
+                    }
                 });
         });
         
@@ -0,0 +1,205 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::config::{file_stem, user_config_dir, ConfigError};
+
+// The built-in library ships inside the binary like the presets and airfoils
+const BUILTIN_MATERIALS: &str = include_str!("../materials/materials.toml");
+
+// FlightParams fields that take a material name or table
+const MATERIAL_FIELDS: [&str; 2] = ["wing_material", "spar_material"];
+
+// Isotropic material, in the units a datasheet gives them
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Material {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub density: f32,  // kg/m³
+    pub modulus: f32,  // GPa
+    pub tensile_strength: f32,  // MPa
+    pub compressive_strength: f32,  // MPa
+    pub shear_strength: f32,  // MPa
+    pub fatigue_limit: f32,  // MPa, fully reversed stress amplitude that survives 10⁷ cycles
+    pub cost_per_kg: f32,  // USD
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MaterialSource {
+    BuiltIn,
+    User(PathBuf),
+}
+
+// A material file holds one or more [[materials]] tables
+#[derive(Serialize, Deserialize)]
+struct MaterialFile {
+    materials: Vec<Material>,
+}
+
+fn builtin_materials() -> &'static [Material] {
+    static MATERIALS: OnceLock<Vec<Material>> = OnceLock::new();
+    MATERIALS.get_or_init(|| {
+        MaterialLibrary::parse(BUILTIN_MATERIALS).unwrap_or_else(|err| panic!("built-in materials: {}", err))
+    })
+}
+
+impl Material {
+    // Built-in materials only; user materials are resolved against a loaded MaterialLibrary
+    pub fn named(name: &str) -> Option<Self> {
+        builtin_materials().iter().find(|material| material.name == name).cloned()
+    }
+
+    // Bending puts one face in tension and the other in compression
+    pub fn bending_strength(&self) -> f32 {
+        self.tensile_strength.min(self.compressive_strength)
+    }
+
+    // Stiffness and bending strength per unit mass, the usual figures of merit for a spar
+    pub fn specific_modulus(&self) -> f32 {
+        self.modulus * 1e9 / self.density.max(1e-6)  // m²/s²
+    }
+
+    pub fn specific_strength(&self) -> f32 {
+        self.bending_strength() * 1e6 / self.density.max(1e-6)  // m²/s²
+    }
+}
+
+// Designs may name a library material such as "Carbon" instead of spelling out its properties
+struct MaterialVisitor;
+
+impl<'de> Visitor<'de> for MaterialVisitor {
+    type Value = Material;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a material name or a table of material properties")
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<Material, E> {
+        Material::named(name).ok_or_else(|| E::custom(format!("unknown material \"{}\"; give its properties as a table or load the design with the library that defines it", name)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Material, A::Error> {
+        Material::deserialize(de::value::MapAccessDeserializer::new(map))
+    }
+}
+
+pub(crate) fn deserialize_material<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Material, D::Error> {
+    deserializer.deserialize_any(MaterialVisitor)
+}
+
+pub fn user_material_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("materials"))
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MaterialLibrary {
+    pub materials: Vec<(Material, MaterialSource)>,
+    pub load_errors: Vec<String>,
+}
+
+impl MaterialLibrary {
+    pub fn parse(text: &str) -> Result<Vec<Material>, ConfigError> {
+        let file: MaterialFile = toml::from_str(text)?;
+        Ok(file.materials)
+    }
+
+    pub fn builtin() -> Self {
+        let materials = builtin_materials().iter().map(|material| (material.clone(), MaterialSource::BuiltIn)).collect();
+        Self { materials, load_errors: Vec::new() }
+    }
+
+    // Built-ins followed by every *.toml material file in the user directory
+    pub fn load() -> Self {
+        let mut library = Self::builtin();
+        if let Some(dir) = user_material_dir() {
+            library.load_dir(&dir);
+        }
+        library
+    }
+
+    pub fn load_dir(&mut self, dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;  // No user materials yet
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            match fs::read_to_string(&path).map_err(ConfigError::from).and_then(|text| Self::parse(&text)) {
+                Ok(materials) => {
+                    for material in materials {
+                        self.insert(material, MaterialSource::User(path.clone()));
+                    }
+                }
+                Err(err) => self.load_errors.push(format!("{}: {}", path.display(), err)),
+            }
+        }
+    }
+
+    // A material with the same name replaces the existing entry
+    fn insert(&mut self, material: Material, source: MaterialSource) {
+        match self.materials.iter_mut().find(|(existing, _)| existing.name == material.name) {
+            Some(existing) => *existing = (material, source),
+            None => self.materials.push((material, source)),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&(Material, MaterialSource)> {
+        self.materials.iter().find(|(material, _)| material.name == name)
+    }
+
+    // Replace material names in a parsed design with their property tables, so deserializing
+    // it needs no library lookup
+    pub(crate) fn expand_names(&self, params: &mut toml::Table) -> Result<(), ConfigError> {
+        for field in MATERIAL_FIELDS {
+            if let Some((material, _)) = params.get(field).and_then(|value| value.as_str()).and_then(|name| self.get(name)) {
+                params.insert(field.to_string(), toml::Value::try_from(material)?);
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn expand_json_names(&self, params: &mut serde_json::Value) -> Result<(), ConfigError> {
+        let Some(params) = params.as_object_mut() else {
+            return Ok(());  // Not a table; the deserializer reports it
+        };
+        for field in MATERIAL_FIELDS {
+            if let Some((material, _)) = params.get(field).and_then(|value| value.as_str()).and_then(|name| self.get(name)) {
+                params.insert(field.to_string(), serde_json::to_value(material)?);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn save_user_material(&mut self, material: &Material) -> Result<PathBuf, ConfigError> {
+        let dir = user_material_dir().ok_or_else(|| {
+            ConfigError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no user configuration directory",
+            ))
+        })?;
+        self.save_material_to(&dir, material)
+    }
+
+    pub fn save_material_to(&mut self, dir: &Path, material: &Material) -> Result<PathBuf, ConfigError> {
+        let path = dir.join(format!("{}.toml", file_stem(&material.name, "material")));
+        let mut material = material.clone();
+        material.name = material.name.trim().to_string();
+        material.description = material.description.trim().to_string();
+
+        let file = MaterialFile { materials: vec![material.clone()] };
+        fs::create_dir_all(dir)?;
+        fs::write(&path, toml::to_string_pretty(&file)?)?;
+        self.insert(material, MaterialSource::User(path.clone()));
+        Ok(path)
+    }
+}
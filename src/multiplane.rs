@@ -0,0 +1,133 @@
+use serde::Serialize;
+
+use crate::numeric::solve_linear;
+use crate::params::{FlightParams, WingLayout};
+use crate::vortex_lattice::Planform;
+
+// Where one lifting surface sits relative to the main wing, and how big it is
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct SurfacePlacement {
+    pub scale: f32,  // Span and chord relative to the main wing
+    pub x_offset: f32,  // m aft of the main wing quarter chord
+    pub z_offset: f32,  // m above the main wing
+    pub incidence: f32,  // deg relative to the main wing root
+}
+
+// Munk's multiplane theory: induced drag D = (1/πq) Σ σij Li Lj / (bi bj), independent of
+// stagger, with the interference factors σ depending only on the vertical gap and the spans
+#[derive(Clone, Debug, Serialize)]
+pub struct MunkInterference {
+    pub spans: Vec<f32>,  // m, projected
+    pub interference: Vec<Vec<f32>>,  // σij, 1 on the diagonal
+    pub optimal_lift_shares: Vec<f32>,  // Split that minimises the induced drag
+    pub minimum_span_efficiency: f32,  // At the optimal split, on the widest span
+}
+
+// Surfaces in order of their index, one per pair of wings; design files with an odd count are rejected
+pub fn surface_placements(params: &FlightParams) -> Vec<SurfacePlacement> {
+    let count = (params.wing_count / 2).max(1);
+    let (gap, stagger, decalage) = (params.wing_gap, params.wing_stagger, params.wing_decalage);
+    (0..count)
+        .map(|index| {
+            let i = index as f32;
+            match params.wing_layout {
+                // One behind another, each a gap higher; the front wing flies at the highest incidence
+                WingLayout::Tandem => SurfacePlacement { scale: 1.0, x_offset: i * stagger, z_offset: i * gap, incidence: -i * decalage },
+                // Stacked a gap apart; positive stagger puts the upper wing ahead, as biplanes are usually rigged
+                WingLayout::Biplane => SurfacePlacement {
+                    scale: 1.0,
+                    x_offset: -i * stagger,
+                    z_offset: i * gap,
+                    incidence: i * decalage * stagger.signum(),
+                },
+                // A smaller foreplane one stagger ahead of the main wing, which carries the rest behind it
+                WingLayout::Canard if index == 0 && count > 1 => SurfacePlacement {
+                    scale: params.canard_scale,
+                    x_offset: -stagger,
+                    z_offset: -gap,
+                    incidence: decalage,
+                },
+                WingLayout::Canard => {
+                    let i = (index as f32 - 1.0).max(0.0);
+                    SurfacePlacement { scale: 1.0, x_offset: i * stagger, z_offset: i * gap, incidence: -i * decalage }
+                }
+            }
+        })
+        .collect()
+}
+
+// Prandtl's fit to Munk's factor for two wings of equal span, normalised so coincident wings give
+// exactly 1, and scaled by the span ratio, which is the exact coplanar value for a short wing
+// inside the wake of a longer one
+pub fn interference_factor(gap: f32, span_1: f32, span_2: f32) -> f32 {
+    let mean_span = 0.5 * (span_1 + span_2).max(1e-6);
+    let gap_ratio = gap.abs() / mean_span;
+    let span_ratio = span_1.min(span_2) / span_1.max(span_2).max(1e-6);
+    (span_ratio * (1.0 - 0.66 * gap_ratio) / (1.0 + 3.7 / 1.055 * gap_ratio)).max(0.0)
+}
+
+impl MunkInterference {
+    pub fn from_planform(planform: &Planform) -> Self {
+        let spans: Vec<f32> = planform.surfaces.iter().map(|surface| surface.span()).collect();
+        let interference: Vec<Vec<f32>> = planform
+            .surfaces
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                planform
+                    .surfaces
+                    .iter()
+                    .enumerate()
+                    .map(|(j, b)| if i == j { 1.0 } else { interference_factor(a.z_offset - b.z_offset, spans[i], spans[j]) })
+                    .collect()
+            })
+            .collect();
+
+        // Minimise sᵀMs with Σs = 1: s = M⁻¹1 / 1ᵀM⁻¹1
+        let matrix: Vec<Vec<f32>> = (0..spans.len())
+            .map(|i| (0..spans.len()).map(|j| interference[i][j] / (spans[i] * spans[j]).max(1e-6)).collect())
+            .collect();
+        let weights = solve_linear(matrix, vec![vec![1.0; spans.len()]]).remove(0);
+        let total: f32 = weights.iter().sum();
+        let optimal_lift_shares = weights.iter().map(|weight| weight / total).collect();
+
+        let mut munk = Self {
+            spans,
+            interference,
+            optimal_lift_shares,
+            minimum_span_efficiency: 1.0,
+        };
+        munk.minimum_span_efficiency = munk.span_efficiency(&munk.optimal_lift_shares);
+        munk
+    }
+
+    // e on the widest span for a given split of the lift between the surfaces, elliptic loading on each
+    pub fn span_efficiency(&self, lift_shares: &[f32]) -> f32 {
+        let reference_span = self.spans.iter().copied().fold(0.0, f32::max);
+        let mut drag = 0.0;
+        for (i, share_i) in lift_shares.iter().enumerate() {
+            for (j, share_j) in lift_shares.iter().enumerate() {
+                drag += self.interference[i][j] * share_i * share_j / (self.spans[i] * self.spans[j]).max(1e-6);
+            }
+        }
+        1.0 / (reference_span.powi(2) * drag).max(1e-9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coincident_wings_interfere_fully() {
+        assert!((interference_factor(0.0, 10.0, 10.0) - 1.0).abs() < 1e-6);
+        assert!((interference_factor(0.0, 5.0, 10.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn interference_fades_with_the_gap() {
+        let factors: Vec<f32> = [0.0, 0.5, 1.0, 2.0, 5.0].iter().map(|&gap| interference_factor(gap, 10.0, 10.0)).collect();
+        assert!(factors.windows(2).all(|pair| pair[1] < pair[0]), "{:?}", factors);
+        assert_eq!(interference_factor(20.0, 10.0, 10.0), 0.0);
+    }
+}
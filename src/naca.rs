@@ -0,0 +1,242 @@
+use std::f32::consts::PI;
+
+use serde::Serialize;
+
+use crate::airfoil::{Polar, PolarPoint};
+use crate::parasite::skin_friction_coefficient;
+
+const SURFACE_POINTS: usize = 60;  // Per surface, cosine spaced
+const THEORY_STATIONS: usize = 400;
+
+// Standard (non-reflexed) five-digit mean lines for design CL 0.3, by maximum-camber position code 1-5
+const FIVE_DIGIT_STANDARD: [(f32, f32); 5] = [
+    (0.0580, 361.400),
+    (0.1260, 51.640),
+    (0.2025, 15.957),
+    (0.2900, 6.643),
+    (0.3910, 3.230),
+];
+// Reflexed mean lines (third digit 1), position codes 2-5: m, k1, k2/k1
+#[allow(clippy::approx_constant)]  // 0.318 is the tabulated camber position, not 1/π
+const FIVE_DIGIT_REFLEXED: [(f32, f32, f32); 4] = [
+    (0.1300, 51.990, 0.000764),
+    (0.2170, 15.793, 0.00677),
+    (0.3180, 6.520, 0.0303),
+    (0.4410, 3.191, 0.1355),
+];
+
+// Smooth-section CLmax near Re 3 million against thickness, after Abbott & von Doenhoff
+const CL_MAX_THICKNESS: [f32; 6] = [0.06, 0.09, 0.12, 0.15, 0.18, 0.21];
+const CL_MAX_SYMMETRIC: [f32; 6] = [0.85, 1.25, 1.55, 1.60, 1.50, 1.40];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MeanLine {
+    FourDigit { camber: f32, position: f32 },
+    FiveDigit { position: f32, k1: f32, k2_over_k1: Option<f32> },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NacaAirfoil {
+    pub designation: String,
+    pub thickness_ratio: f32,
+    mean_line: MeanLine,
+}
+
+// Thin-airfoil theory results for the mean line
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct ThinAirfoilEstimate {
+    pub zero_lift_angle: f32,  // deg
+    pub lift_slope: f32,  // per rad, 2π corrected for thickness and the boundary layer
+    pub ideal_lift_coefficient: f32,
+    pub moment_coefficient: f32,  // About the quarter chord
+}
+
+impl NacaAirfoil {
+    // "2412", "NACA 23012", "naca0012"
+    pub fn parse(designation: &str) -> Result<Self, String> {
+        let digits: String = designation
+            .trim()
+            .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("'{}' is not a NACA 4- or 5-digit designation", designation));
+        }
+        let digit = |index: usize| digits.as_bytes()[index] - b'0';
+        let thickness = |start: usize| digits[start..].parse::<f32>().unwrap_or(0.0) / 100.0;
+
+        let (mean_line, thickness_ratio) = match digits.len() {
+            4 => {
+                let camber = digit(0) as f32 / 100.0;
+                let position = digit(1) as f32 / 10.0;
+                if camber > 0.0 && position == 0.0 {
+                    return Err(format!("NACA {}: cambered sections need a camber position", digits));
+                }
+                (MeanLine::FourDigit { camber, position }, thickness(2))
+            }
+            5 => {
+                let design_cl = 0.15 * digit(0) as f32;
+                let position_code = digit(1) as usize;
+                let scale = design_cl / 0.3;
+                let mean_line = match (digit(2), position_code) {
+                    (0, 1..=5) => {
+                        let (position, k1) = FIVE_DIGIT_STANDARD[position_code - 1];
+                        MeanLine::FiveDigit { position, k1: k1 * scale, k2_over_k1: None }
+                    }
+                    (1, 2..=5) => {
+                        let (position, k1, k2_over_k1) = FIVE_DIGIT_REFLEXED[position_code - 2];
+                        MeanLine::FiveDigit { position, k1: k1 * scale, k2_over_k1: Some(k2_over_k1) }
+                    }
+                    _ => return Err(format!("NACA {}: unsupported five-digit mean line", digits)),
+                };
+                (mean_line, thickness(3))
+            }
+            _ => return Err(format!("'{}' is not a NACA 4- or 5-digit designation", designation)),
+        };
+
+        if thickness_ratio <= 0.0 {
+            return Err(format!("NACA {}: thickness must be greater than zero", digits));
+        }
+        Ok(Self {
+            designation: format!("NACA {}", digits),
+            thickness_ratio,
+            mean_line,
+        })
+    }
+
+    // Mean-line height and slope at chordwise station x (0-1)
+    pub fn camber_line(&self, x: f32) -> (f32, f32) {
+        match self.mean_line {
+            MeanLine::FourDigit { camber, position } => {
+                if camber == 0.0 {
+                    (0.0, 0.0)
+                } else if x < position {
+                    let k = camber / position.powi(2);
+                    (k * (2.0 * position * x - x * x), 2.0 * k * (position - x))
+                } else {
+                    let k = camber / (1.0 - position).powi(2);
+                    (k * (1.0 - 2.0 * position + 2.0 * position * x - x * x), 2.0 * k * (position - x))
+                }
+            }
+            MeanLine::FiveDigit { position: m, k1, k2_over_k1: None } => {
+                if x < m {
+                    (k1 / 6.0 * (x.powi(3) - 3.0 * m * x * x + m * m * (3.0 - m) * x),
+                     k1 / 6.0 * (3.0 * x * x - 6.0 * m * x + m * m * (3.0 - m)))
+                } else {
+                    (k1 * m.powi(3) / 6.0 * (1.0 - x), -k1 * m.powi(3) / 6.0)
+                }
+            }
+            MeanLine::FiveDigit { position: m, k1, k2_over_k1: Some(k21) } => {
+                let tail = k21 * (1.0 - m).powi(3) + m.powi(3);
+                if x < m {
+                    (k1 / 6.0 * ((x - m).powi(3) - tail * x + m.powi(3)),
+                     k1 / 6.0 * (3.0 * (x - m).powi(2) - tail))
+                } else {
+                    (k1 / 6.0 * (k21 * (x - m).powi(3) - tail * x + m.powi(3)),
+                     k1 / 6.0 * (3.0 * k21 * (x - m).powi(2) - tail))
+                }
+            }
+        }
+    }
+
+    // Half-thickness distribution with the standard open trailing edge
+    pub fn half_thickness(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        5.0 * self.thickness_ratio
+            * (0.2969 * x.sqrt() - 0.1260 * x - 0.3516 * x.powi(2) + 0.2843 * x.powi(3) - 0.1015 * x.powi(4))
+    }
+
+    // Unit-chord coordinates in Selig order, thickness applied normal to the mean line
+    pub fn coordinates(&self) -> Vec<[f32; 2]> {
+        let surface = |sign: f32| {
+            (0..=SURFACE_POINTS).map(move |i| {
+                let x = 0.5 * (1.0 - (PI * i as f32 / SURFACE_POINTS as f32).cos());
+                let (y_camber, slope) = self.camber_line(x);
+                let angle = slope.atan();
+                let half_thickness = self.half_thickness(x);
+                [x - sign * half_thickness * angle.sin(), y_camber + sign * half_thickness * angle.cos()]
+            })
+        };
+        let upper: Vec<[f32; 2]> = surface(1.0).collect();
+        upper.into_iter().rev().chain(surface(-1.0).skip(1)).collect()
+    }
+
+    // Glauert integrals over the mean-line slope with x = (1 - cos θ)/2
+    pub fn thin_airfoil_estimate(&self) -> ThinAirfoilEstimate {
+        let step = PI / THEORY_STATIONS as f32;
+        let (mut zero_lift, mut a1, mut a2) = (0.0, 0.0, 0.0);
+        for i in 0..THEORY_STATIONS {
+            let theta = (i as f32 + 0.5) * step;
+            let slope = self.camber_line(0.5 * (1.0 - theta.cos())).1;
+            zero_lift += slope * (theta.cos() - 1.0) * step;
+            a1 += slope * theta.cos() * step;
+            a2 += slope * (2.0 * theta).cos() * step;
+        }
+        let zero_lift_angle = -zero_lift / PI;
+        let (a1, a2) = (2.0 / PI * a1, 2.0 / PI * a2);
+
+        ThinAirfoilEstimate {
+            zero_lift_angle: zero_lift_angle.to_degrees(),
+            // Thickness raises the potential-flow slope, the boundary layer takes about 10% back
+            lift_slope: 2.0 * PI * (1.0 + 0.77 * self.thickness_ratio) * 0.9,
+            ideal_lift_coefficient: PI * a1,
+            moment_coefficient: PI / 4.0 * (a2 - a1),
+        }
+    }
+
+    // Empirical CLmax from thickness, camber and Reynolds number
+    pub fn estimated_cl_max(&self, reynolds: f32) -> f32 {
+        let table = &CL_MAX_THICKNESS;
+        let t = self.thickness_ratio.clamp(table[0], table[table.len() - 1]);
+        let index = table.iter().position(|&value| value >= t).unwrap_or(table.len() - 1).max(1);
+        let fraction = (t - table[index - 1]) / (table[index] - table[index - 1]);
+        let symmetric = CL_MAX_SYMMETRIC[index - 1] + (CL_MAX_SYMMETRIC[index] - CL_MAX_SYMMETRIC[index - 1]) * fraction;
+        let camber_gain = 0.3 * self.thin_airfoil_estimate().ideal_lift_coefficient.max(0.0);
+        let reynolds_factor = (reynolds.max(1e4) / 3.0e6).powf(0.12).clamp(0.6, 1.08);
+        (symmetric + camber_gain) * reynolds_factor
+    }
+
+    // Minimum drag from flat-plate skin friction on both surfaces times a thickness form factor
+    pub fn estimated_cd_min(&self, reynolds: f32) -> f32 {
+        let t = self.thickness_ratio;
+        2.0 * skin_friction_coefficient(reynolds) * (1.0 + 2.0 * t + 60.0 * t.powi(4))
+    }
+
+    // Synthetic polar: thin-airfoil lift line rounded into a stall, quadratic drag about the ideal CL
+    pub fn estimated_polar(&self, reynolds: f32) -> Polar {
+        let theory = self.thin_airfoil_estimate();
+        let slope = theory.lift_slope.to_radians();  // per deg
+        let cl_max = self.estimated_cl_max(reynolds);
+        let cd_min = self.estimated_cd_min(reynolds);
+        let stall_angle = theory.zero_lift_angle + 1.25 * cl_max / slope;
+
+        let attached_cl = |alpha: f32| {
+            let linear = slope * (alpha - theory.zero_lift_angle);
+            let knee = 0.7 * cl_max;
+            if linear <= knee {
+                linear
+            } else {
+                knee + 0.3 * cl_max * ((linear - knee) / (0.3 * cl_max)).tanh()
+            }
+        };
+
+        let first = (theory.zero_lift_angle - 4.0).floor();
+        let last = stall_angle.ceil() + 4.0;
+        let points = (0..=(last - first) as usize)
+            .map(|i| {
+                let alpha = first + i as f32;
+                let beyond_stall = (alpha - stall_angle).max(0.0);
+                let cl = if beyond_stall > 0.0 { attached_cl(stall_angle) - 0.06 * beyond_stall } else { attached_cl(alpha) };
+                let separation = if beyond_stall > 0.0 { 1.0 } else { ((cl / cl_max - 0.85) / 0.15).max(0.0) };
+                PolarPoint {
+                    alpha,
+                    cl,
+                    cd: cd_min + 0.007 * (cl - theory.ideal_lift_coefficient).powi(2) + 0.012 * separation.powi(2) + 0.025 * beyond_stall,
+                    cm: theory.moment_coefficient - 0.012 * beyond_stall,
+                }
+            })
+            .collect();
+        Polar { reynolds, points }
+    }
+}
@@ -0,0 +1,38 @@
+// Gaussian elimination with partial pivoting, one solution per right-hand side
+pub(crate) fn solve_linear(mut matrix: Vec<Vec<f32>>, mut rhs: Vec<Vec<f32>>) -> Vec<Vec<f32>> {
+    let size = matrix.len();
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))
+            .unwrap_or(column);
+        matrix.swap(column, pivot);
+        for values in rhs.iter_mut() {
+            values.swap(column, pivot);
+        }
+        let diagonal = matrix[column][column];
+        if diagonal.abs() < 1e-12 {
+            continue;
+        }
+        for row in column + 1..size {
+            let factor = matrix[row][column] / diagonal;
+            if factor == 0.0 {
+                continue;
+            }
+            let (upper, lower) = matrix.split_at_mut(row);
+            for (target, &source) in lower[0][column..].iter_mut().zip(&upper[column][column..]) {
+                *target -= factor * source;
+            }
+            for values in rhs.iter_mut() {
+                values[row] -= factor * values[column];
+            }
+        }
+    }
+    for values in rhs.iter_mut() {
+        for row in (0..size).rev() {
+            let sum: f32 = (row + 1..size).map(|k| matrix[row][k] * values[k]).sum();
+            let diagonal = matrix[row][row];
+            values[row] = if diagonal.abs() < 1e-12 { 0.0 } else { (values[row] - sum) / diagonal };
+        }
+    }
+    rhs
+}
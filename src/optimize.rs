@@ -0,0 +1,85 @@
+use crate::params::FlightParams;
+use crate::physics::calculate_comprehensive_flight_analysis;
+
+pub fn optimize_parameters(base_params: &FlightParams) -> FlightParams {
+    let mut best_params = base_params.clone();
+    let mut best_score = score_configuration(&best_params);
+    
+    let param_ranges = vec![
+        ("wing_span", 2.0, 8.0, 0.5),
+        ("wing_chord", 0.5, 3.0, 0.25),
+        ("motor_power", 0.0, 5000.0, 500.0),
+        ("forward_speed", 8.0, 20.0, 1.0),
+    ];
+    
+    for _ in 0..10 {
+        for (param_name, min_val, max_val, step) in &param_ranges {
+            let mut val = *min_val;
+            while val <= *max_val {
+                let mut test_params = best_params.clone();
+                
+                match *param_name {
+                    "wing_span" => test_params.resize_wing(val, test_params.planform().mean_chord()),
+                    "wing_chord" => test_params.resize_wing(test_params.planform().span(), val),
+                    "motor_power" => test_params.motor_power = val,
+                    "forward_speed" => test_params.forward_speed = val,
+                    _ => {}
+                }
+                
+                let score = score_configuration(&test_params);
+                if score > best_score {
+                    best_score = score;
+                    best_params = test_params;
+                }
+                
+                val += step;
+            }
+        }
+    }
+    
+    best_params
+}
+
+pub fn score_configuration(params: &FlightParams) -> f32 {
+    let analysis = calculate_comprehensive_flight_analysis(params);
+    
+    let mut score = 0.0;
+    
+    // Primary objective: sustained flight capability
+    if analysis.can_sustain_level_flight && analysis.structural.structural_feasible {
+        score += 1000.0;
+    } else if analysis.can_takeoff && analysis.structural.structural_feasible {
+        score += 300.0;  // Takeoff without sustain is less valuable
+    }
+    
+    // Efficiency metrics (minimize power requirements)
+    let power_efficiency = if analysis.total_power_required > 0.0 {
+        1000.0 / analysis.total_power_required  // Higher score for lower power
+    } else {
+        0.0
+    };
+    score += power_efficiency;
+    
+    // Mass penalty (lighter is better)
+    score -= analysis.total_mass * 5.0;
+    
+    // Wing loading penalty (lower wing loading is better for human flight)
+    score -= analysis.wing_loading * 0.1;
+    
+    // Stall speed penalty (lower stall speed is safer)
+    score -= analysis.stall_speed * 10.0;
+    
+    // Bonus for realistic motor endurance
+    if analysis.motor_flight_time > 10.0 && analysis.motor_flight_time < 60.0 {
+        score += 100.0;
+    }
+    
+    // Penalty for excessive takeoff distance
+    if analysis.takeoff_distance < 100.0 {
+        score += 100.0;
+    } else if analysis.takeoff_distance > 500.0 {
+        score -= (analysis.takeoff_distance - 500.0) * 0.1;
+    }
+    
+    score.max(0.0)  // Ensure non-negative scores
+}
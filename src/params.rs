@@ -0,0 +1,354 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::airfoil::AirfoilData;
+use crate::atmosphere::Atmosphere;
+use crate::multiplane::surface_placements;
+use crate::naca::NacaAirfoil;
+use crate::laminate::Ply;
+use crate::materials::{deserialize_material, Material};
+use crate::planform::{WingPanel, WingPlanform};
+
+// Unknown keys are errors, so a misspelt field or a preset file fed in as a design is not
+// silently replaced by its default
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FlightParams {
+    pub pilot_mass: f32,
+    pub pilot_power_sustained: f32,
+    pub pilot_power_burst: f32,
+    pub pilot_profile: PilotProfile,  // Custom uses the sustained/burst ratings above
+    
+    #[serde(deserialize_with = "deserialize_wing_count")]
+    pub wing_count: u32,  // Left and right wings, so always even
+    pub wing_span: f32,  // m, root to tip of one wing; wings pair up into lifting surfaces
+    pub wing_chord: f32,  // m, mean chord
+    pub wing_thickness_ratio: f32,
+    pub wing_taper_ratio: f32,  // Tip chord over root chord
+    pub wing_twist: f32,  // deg of washout at the tip
+    pub wing_dihedral: f32,  // deg
+    pub wing_sweep: f32,  // deg, quarter-chord line
+    pub wing_panels: Vec<WingPanel>,  // Root to tip; when empty the wing is one panel from the values above
+    pub wing_layout: WingLayout,  // How wing pairs beyond the first are arranged
+    pub wing_gap: f32,  // m, vertical spacing between successive wing pairs
+    pub wing_stagger: f32,  // m, fore-aft spacing between successive wing pairs
+    pub wing_decalage: f32,  // deg, incidence of each wing pair over the one behind it
+    pub canard_scale: f32,  // Foreplane span and chord over the main wing's, Canard only
+    
+    #[serde(deserialize_with = "deserialize_material")]
+    pub wing_material: Material,  // Skin
+    #[serde(deserialize_with = "deserialize_material")]
+    pub spar_material: Material,
+    pub spar_section: SparSection,
+    pub spar_wall_thickness: f32,  // mm, tube wall of a spar_material tube
+    pub spar_layup: Vec<Ply>,  // Inside out; when given, the spar is this laminate instead of spar_material
+    pub wing_safety_factor: f32,
+    
+    pub pilot_position: PilotPosition,
+    pub fairing: FairingType,
+    pub tail_boom_length: f32,  // m
+    pub tail_boom_diameter: f32,  // m
+    pub tail_area: f32,  // m², horizontal and vertical tail together
+    pub landing_gear: LandingGear,
+    pub wheel_count: u32,
+    pub bracing_wire_length: f32,  // m, all wires together
+    pub bracing_wire_diameter: f32,  // mm
+    
+    pub motor_power: f32,
+    pub motor_mass: f32,
+    pub battery_capacity: f32,  // Wh
+    pub battery_chemistry: BatteryChemistry,
+    pub battery_depth_of_discharge: f32,  // Usable fraction, 0-1
+    pub battery_series_cells: u32,
+    pub motor_efficiency: f32,
+    
+    pub propeller_diameter: f32,  // m
+    pub propeller_pitch: f32,  // m of geometric advance per revolution
+    pub propeller_blades: u32,
+    pub propeller_blade_chord: f32,  // m at 75% radius
+    pub propeller_rpm: f32,  // Full-throttle shaft speed
+    pub drivetrain_efficiency: f32,  // Pedals to propeller shaft
+    
+    pub airfoil: AirfoilSection,
+    pub airfoil_cl_max: f32,  // Custom section only
+    pub airfoil_cd_min: f32,  // Custom section only
+    pub airfoil_zero_lift_angle: f32,  // deg, Custom section only
+    
+    pub forward_speed: f32,
+    pub on_approach: bool,  // Gliding in to land at forward_speed, power off, instead of holding level flight
+    pub flapping_frequency: f32,
+    pub flapping_amplitude: f32,
+    pub flapping_upstroke_ratio: f32,  // Upstroke duration over downstroke duration
+    pub flapping_pitch_amplitude: f32,  // deg of feathering at the tip, either side
+    pub flapping_pitch_lag: f32,  // deg the feathering lags the stroke velocity
+    pub flapping_stroke_plane: f32,  // deg the stroke is tilted forward from vertical
+    pub flapping_phase_offset: f32,  // deg each wing pair lags the one ahead of it
+    pub flapping_spring_stiffness: f32,  // N·m/rad about the root hinge of a main wing
+    pub flapping_damping_ratio: f32,  // Spring losses as a fraction of critical damping
+    pub wind_speed: f32,
+    
+    pub altitude: f32,  // m above mean sea level
+    pub temperature_offset: f32,  // K relative to ISA
+    pub relative_humidity: f32,  // 0-1
+    
+    pub runway_surface: RunwaySurface,
+    pub runway_slope: f32,  // Rise over run, positive uphill in the takeoff direction
+    pub braking_friction: f32,  // Effective friction coefficient with brakes applied
+    
+    // Section imported from coordinate and XFOIL polar files, used when `airfoil` is Imported
+    pub imported_airfoil: Option<AirfoilData>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PilotProfile {
+    Custom,
+    Recreational,
+    TrainedCyclist,
+    Elite,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PilotPosition {
+    Upright,
+    Recumbent,
+    Prone,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum FairingType {
+    Open,
+    NoseCone,  // Windscreen ahead of the pilot only
+    Enclosed,  // Streamlined body around the pilot
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum LandingGear {
+    Fixed,
+    Faired,
+    Retracted,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum WingLayout {
+    Tandem,  // Equal wings one behind another
+    Biplane,  // Equal wings stacked one above another
+    Canard,  // Small foreplane ahead of the main wing
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SparSection {
+    Rectangular,  // Solid 2 cm wide beam filling the wing depth
+    Tube,  // Round tube as deep as the wing, wall from the layup or spar_wall_thickness
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AirfoilSection {
+    Custom,
+    Dae11,
+    Fx76Mp140,
+    Naca0012,
+    Naca4412,
+    Naca(String),  // Any 4- or 5-digit designation, estimated from thin-airfoil theory
+    Imported,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BatteryChemistry {
+    LiPo,
+    LiIon,
+    LiFePO4,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RunwaySurface {
+    Paved,
+    Dirt,
+    ShortGrass,
+    LongGrass,
+}
+
+impl RunwaySurface {
+    // Rolling resistance for narrow, high-pressure bicycle-type wheels
+    pub fn rolling_friction(&self) -> f32 {
+        match self {
+            RunwaySurface::Paved => 0.02,
+            RunwaySurface::Dirt => 0.04,
+            RunwaySurface::ShortGrass => 0.05,
+            RunwaySurface::LongGrass => 0.10,
+        }
+    }
+}
+
+// Wings pair up into lifting surfaces, so an odd count has no layout
+fn deserialize_wing_count<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let count = u32::deserialize(deserializer)?;
+    if count == 0 || count % 2 != 0 {
+        return Err(de::Error::custom(format!("wing_count must be an even number of wings, one pair per lifting surface, not {}", count)));
+    }
+    Ok(count)
+}
+
+impl FlightParams {
+    pub fn planform(&self) -> WingPlanform {
+        WingPlanform::from_params(self)
+    }
+    
+    pub fn wing_area(&self) -> f32 {
+        self.equivalent_wing_count() * self.planform().area()
+    }
+    
+    // Number of main-wing-sized wings with the same total area; a canard foreplane counts for less
+    pub fn equivalent_wing_count(&self) -> f32 {
+        2.0 * surface_placements(self).iter().map(|placement| placement.scale.powi(2)).sum::<f32>()
+    }
+    
+    // Scale the wing to a new span and mean chord, keeping the shape of a multi-panel planform
+    pub fn resize_wing(&mut self, span: f32, chord: f32) {
+        let planform = self.planform();
+        let (span_scale, chord_scale) = (span / planform.span().max(1e-6), chord / planform.mean_chord().max(1e-6));
+        for panel in &mut self.wing_panels {
+            panel.span *= span_scale;
+            panel.root_chord *= chord_scale;
+            panel.tip_chord *= chord_scale;
+        }
+        self.wing_span = span;
+        self.wing_chord = chord;
+    }
+    
+    pub fn atmosphere(&self) -> Atmosphere {
+        Atmosphere::isa(self.altitude, self.temperature_offset, self.relative_humidity)
+    }
+    
+    // Shaft power delivered to the propeller by the pilot and the motor
+    pub fn pilot_shaft_power(&self, pilot_power: f32) -> f32 {
+        pilot_power * self.drivetrain_efficiency
+    }
+    
+    pub fn motor_shaft_power(&self) -> f32 {
+        self.motor_power * self.motor_efficiency
+    }
+    
+    pub fn rolling_friction(&self) -> f32 {
+        self.runway_surface.rolling_friction()
+    }
+    
+    // Positive for a headwind; `wind_speed` is measured along the direction of travel
+    pub fn headwind(&self) -> f32 {
+        -self.wind_speed
+    }
+    
+    // Switch to an imported section; its own thickness replaces the slider value
+    pub fn use_imported_airfoil(&mut self, airfoil: AirfoilData) {
+        self.wing_thickness_ratio = airfoil.thickness_ratio;
+        self.imported_airfoil = Some(airfoil);
+        self.airfoil = AirfoilSection::Imported;
+    }
+    
+    // Switch to a generated NACA section; the thickness comes from the designation
+    pub fn use_naca_airfoil(&mut self, designation: &str) -> Result<(), String> {
+        let naca = NacaAirfoil::parse(designation)?;
+        self.wing_thickness_ratio = naca.thickness_ratio;
+        // Prefer measured polars when the database has the section
+        self.airfoil = match naca.designation.trim_start_matches("NACA ") {
+            "0012" => AirfoilSection::Naca0012,
+            "4412" => AirfoilSection::Naca4412,
+            digits => AirfoilSection::Naca(digits.to_string()),
+        };
+        Ok(())
+    }
+    
+    // Tip-to-tip span of a wing pair, projected onto the horizontal
+    pub fn reference_span(&self) -> f32 {
+        2.0 * self.planform().projected_span()
+    }
+    
+    // Reference span² over the area of all wings; interference between pairs is left to the span loading
+    pub fn aspect_ratio(&self) -> f32 {
+        self.reference_span().powi(2) / self.wing_area()
+    }
+}
+
+impl Default for FlightParams {
+    fn default() -> Self {
+        Self {
+            pilot_mass: 80.0,
+            pilot_power_sustained: 200.0,
+            pilot_power_burst: 600.0,
+            pilot_profile: PilotProfile::Custom,
+            
+            wing_count: 4,
+            wing_span: 3.0,
+            wing_chord: 1.5,
+            wing_thickness_ratio: 0.12,
+            wing_taper_ratio: 1.0,
+            wing_twist: 0.0,
+            wing_dihedral: 0.0,
+            wing_sweep: 0.0,
+            wing_panels: Vec::new(),
+            wing_layout: WingLayout::Tandem,
+            wing_gap: 0.5,
+            wing_stagger: 2.0,
+            wing_decalage: 0.0,
+            canard_scale: 0.4,
+            
+            wing_material: Material::named("Fabric").expect("built-in material"),
+            spar_material: Material::named("Carbon").expect("built-in material"),
+            spar_section: SparSection::Rectangular,
+            spar_wall_thickness: 1.5,
+            spar_layup: Vec::new(),
+            wing_safety_factor: 2.0,
+            
+            pilot_position: PilotPosition::Upright,
+            fairing: FairingType::Open,
+            tail_boom_length: 4.0,
+            tail_boom_diameter: 0.06,
+            tail_area: 2.0,
+            landing_gear: LandingGear::Fixed,
+            wheel_count: 2,
+            bracing_wire_length: 0.0,
+            bracing_wire_diameter: 1.5,
+            
+            motor_power: 2000.0,
+            motor_mass: 8.0,
+            battery_capacity: 500.0,
+            battery_chemistry: BatteryChemistry::LiPo,
+            battery_depth_of_discharge: 0.8,
+            battery_series_cells: 12,
+            motor_efficiency: 0.85,
+            
+            propeller_diameter: 2.0,
+            propeller_pitch: 3.4,
+            propeller_blades: 2,
+            propeller_blade_chord: 0.2,
+            propeller_rpm: 380.0,
+            drivetrain_efficiency: 0.95,  // Chain and gearbox
+            
+            airfoil: AirfoilSection::Custom,
+            airfoil_cl_max: 1.6,
+            airfoil_cd_min: 0.008,
+            airfoil_zero_lift_angle: -4.0,  // Moderately cambered low-speed section
+            
+            forward_speed: 12.0,
+            on_approach: false,
+            flapping_frequency: 2.0,
+            flapping_amplitude: 25.0,
+            flapping_upstroke_ratio: 1.0,  // Symmetric stroke
+            flapping_pitch_amplitude: 0.0,
+            flapping_pitch_lag: 0.0,  // Nose-down at mid-downstroke
+            flapping_stroke_plane: 0.0,
+            flapping_phase_offset: 0.0,  // Pairs beat together
+            flapping_spring_stiffness: 0.0,  // No elastic storage
+            flapping_damping_ratio: 0.02,  // Composite spring, about 12% of the stored energy lost per cycle
+            wind_speed: 0.0,
+            
+            altitude: 0.0,
+            temperature_offset: 0.0,
+            relative_humidity: 0.0,
+            
+            runway_surface: RunwaySurface::Paved,
+            runway_slope: 0.0,
+            braking_friction: 0.1,  // Light wheel brake, typical for human-powered aircraft
+            
+            imported_airfoil: None,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use serde::Serialize;
+
+use crate::params::{FairingType, FlightParams, LandingGear, PilotPosition};
+
+const TAIL_THICKNESS_RATIO: f32 = 0.09;  // Thin symmetric sections
+const TAIL_ASPECT_RATIO: f32 = 4.0;  // Sets the tail chord for its Reynolds number
+const WIRE_DRAG_COEFFICIENT: f32 = 1.1;  // Round wire at subcritical Reynolds number
+const NOSE_CONE_REDUCTION: f32 = 0.6;  // Share of the open pilot drag left behind a nose fairing
+
+// Drag area (CD × frontal area) of one airframe component
+#[derive(Clone, Debug, Serialize)]
+pub struct DragComponent {
+    pub name: &'static str,
+    pub wetted_area: f32,  // m², zero for bluff bodies rated by frontal area
+    pub form_factor: f32,  // 1 for bluff bodies
+    pub drag_area: f32,  // m²
+}
+
+// Component buildup: streamlined parts as Cf × FF × Q × S_wet, bluff parts as measured CdA
+#[derive(Clone, Debug, Serialize)]
+pub struct ParasiteDrag {
+    pub airspeed: f32,  // m/s the skin friction was evaluated at
+    pub components: Vec<DragComponent>,
+    pub drag_area: f32,  // m², all components
+    pub drag_coefficient: f32,  // On the wing reference area
+}
+
+impl PilotPosition {
+    // Frontal area and drag coefficient of an unfaired pilot
+    pub fn frontal_drag(&self) -> (f32, f32) {
+        match self {
+            PilotPosition::Upright => (0.50, 0.90),  // m², cyclist on the hoods
+            PilotPosition::Recumbent => (0.38, 0.80),
+            PilotPosition::Prone => (0.28, 0.75),
+        }
+    }
+
+    // Length and maximum diameter of the smallest enclosing fairing, m
+    pub fn fairing_size(&self) -> (f32, f32) {
+        match self {
+            PilotPosition::Upright => (2.0, 0.75),
+            PilotPosition::Recumbent => (2.3, 0.62),
+            PilotPosition::Prone => (2.6, 0.50),
+        }
+    }
+}
+
+impl LandingGear {
+    // Drag area of one wheel, m²
+    pub fn wheel_drag_area(&self) -> f32 {
+        match self {
+            LandingGear::Fixed => 0.02,  // Exposed bicycle wheel and fork
+            LandingGear::Faired => 0.006,  // Spatted
+            LandingGear::Retracted => 0.0,
+        }
+    }
+}
+
+// Flat-plate skin friction, transitional above Re 5×10⁵ and never below the laminar value
+pub fn skin_friction_coefficient(reynolds: f32) -> f32 {
+    let reynolds = reynolds.max(1e4);
+    let transitional = 0.074 / reynolds.powf(0.2) - 1742.0 / reynolds;
+    let laminar = 1.328 / reynolds.sqrt();
+    transitional.max(laminar)
+}
+
+// Streamlined body of revolution: wetted area and form factor from its fineness ratio (Raymer)
+fn body_of_revolution(length: f32, diameter: f32) -> (f32, f32) {
+    let fineness = (length / diameter.max(1e-3)).max(2.5);
+    let wetted_area = std::f32::consts::PI * diameter * length * (1.0 - 2.0 / fineness).powf(2.0 / 3.0) * (1.0 + 1.0 / fineness.powi(2));
+    let form_factor = 1.0 + 60.0 / fineness.powi(3) + fineness / 400.0;
+    (wetted_area, form_factor)
+}
+
+impl ParasiteDrag {
+    // Evaluated at the configured airspeed
+    pub fn from_params(params: &FlightParams) -> Self {
+        Self::at_airspeed(params, (params.forward_speed - params.wind_speed).max(0.0))
+    }
+
+    pub fn at_airspeed(params: &FlightParams, airspeed: f32) -> Self {
+        let kinematic_viscosity = params.atmosphere().kinematic_viscosity;
+        let friction = |length: f32| skin_friction_coefficient(airspeed.max(1.0) * length / kinematic_viscosity);
+        let streamlined = |name, length: f32, wetted_area: f32, form_factor: f32, interference: f32| DragComponent {
+            name,
+            wetted_area,
+            form_factor,
+            drag_area: friction(length) * form_factor * interference * wetted_area,
+        };
+        let bluff = |name, drag_area: f32| DragComponent { name, wetted_area: 0.0, form_factor: 1.0, drag_area };
+
+        let mut components = Vec::new();
+        let (frontal_area, pilot_cd) = params.pilot_position.frontal_drag();
+        match params.fairing {
+            FairingType::Open => components.push(bluff("Pilot", frontal_area * pilot_cd)),
+            FairingType::NoseCone => components.push(bluff("Pilot (nose cone)", NOSE_CONE_REDUCTION * frontal_area * pilot_cd)),
+            FairingType::Enclosed => {
+                let (length, diameter) = params.pilot_position.fairing_size();
+                let (wetted_area, form_factor) = body_of_revolution(length, diameter);
+                // Canopy joins and the wheel opening add about 10%
+                components.push(streamlined("Fairing", length, wetted_area, form_factor, 1.1));
+            }
+        }
+
+        if params.tail_boom_length > 0.0 {
+            let (length, diameter) = (params.tail_boom_length, params.tail_boom_diameter);
+            let (_, form_factor) = body_of_revolution(length, diameter);
+            components.push(streamlined("Tail Boom", length, std::f32::consts::PI * diameter * length, form_factor, 1.0));
+        }
+
+        if params.tail_area > 0.0 {
+            let chord = (params.tail_area / TAIL_ASPECT_RATIO).sqrt();
+            let t = TAIL_THICKNESS_RATIO;
+            let form_factor = 1.0 + 2.0 * t + 60.0 * t.powi(4);
+            // Both sides wetted, slightly more for the thickness; 5% for the junctions
+            components.push(streamlined("Tail Surfaces", chord, 2.04 * params.tail_area, form_factor, 1.05));
+        }
+
+        if params.wheel_count > 0 && params.landing_gear != LandingGear::Retracted {
+            components.push(bluff("Wheels", params.wheel_count as f32 * params.landing_gear.wheel_drag_area()));
+        }
+
+        if params.bracing_wire_length > 0.0 {
+            let diameter = params.bracing_wire_diameter / 1000.0;
+            components.push(bluff("Bracing Wires", WIRE_DRAG_COEFFICIENT * diameter * params.bracing_wire_length));
+        }
+
+        let drag_area = components.iter().map(|component| component.drag_area).sum();
+        Self {
+            airspeed,
+            components,
+            drag_area,
+            drag_coefficient: drag_area / params.wing_area().max(1e-6),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use serde::Serialize;
+
+use crate::airfoil::SectionPolar;
+use crate::params::FlightParams;
+use crate::physics::level_flight_aero;
+use crate::pilot::PowerDurationCurve;
+use crate::propeller::Propeller;
+use crate::structure::calculate_structural_properties;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct SpeedPoint {
+    pub airspeed: f32,
+    pub angle_of_attack: f32,  // deg
+    pub lift_coefficient: f32,
+    pub drag_coefficient: f32,
+    pub drag_force: f32,
+    pub lift_to_drag: f32,
+    pub power_to_overcome_drag: f32,
+    pub propeller_efficiency: f32,
+    pub power_required: f32,  // Shaft power, including propeller losses and flapping
+    pub stalled: bool,  // CLmax reached before L = W
+    pub propeller_limited: bool,  // Propeller short of the thrust at full RPM; power_required is at its limit
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SpeedSweep {
+    pub points: Vec<SpeedPoint>,
+    pub stall_speed: f32,
+
+    pub v_min_power: Option<f32>,
+    pub min_power_required: f32,
+    pub v_max_lift_to_drag: Option<f32>,
+    pub max_lift_to_drag: f32,
+    pub v_max: Option<f32>,  // Fastest level-flight speed on sustained power, None when out of reach or above the sweep
+    pub v_max_above_sweep: bool,  // Sustained power still covers the top of the sweep
+
+    // Horizontal available-power lines, as shaft power after the drivetrain
+    pub pilot_sustained_power: f32,
+    pub pilot_burst_power: f32,
+    pub motor_shaft_power: f32,
+    pub sustained_power_available: f32,
+}
+
+// Default range covers the stall region up to well past any human-powered cruise speed
+pub fn calculate_speed_sweep(params: &FlightParams) -> SpeedSweep {
+    calculate_speed_sweep_range(params, 1.0, 35.0, 120)
+}
+
+// Level-flight power required and L/D versus true airspeed (wind has no effect on the polar)
+pub fn calculate_speed_sweep_range(params: &FlightParams, min_speed: f32, max_speed: f32, steps: usize) -> SpeedSweep {
+    let structural = calculate_structural_properties(params);
+    let atmosphere = params.atmosphere();
+    let weight_force = (params.pilot_mass + structural.total_structural_mass) * 9.81;
+    let stall_speed = (2.0 * weight_force / (atmosphere.density * params.wing_area() * SectionPolar::from_params(params).cl_max)).sqrt();
+    let propeller = Propeller::from_params(params);
+
+    let steps = steps.max(2);
+    let min_speed = min_speed.max(0.1);
+    let max_speed = max_speed.max(min_speed + 0.1);
+
+    let points: Vec<SpeedPoint> = (0..steps)
+        .map(|i| {
+            let airspeed = min_speed + (max_speed - min_speed) * i as f32 / (steps - 1) as f32;
+            let aero = level_flight_aero(params, atmosphere.density, weight_force, airspeed);
+            let power_to_overcome_drag = aero.drag_force * airspeed;
+            let propulsion = propeller.power_for_thrust(atmosphere.density, (aero.drag_force - aero.flapping_thrust).max(0.0), airspeed);
+            SpeedPoint {
+                airspeed,
+                angle_of_attack: aero.angle_of_attack,
+                lift_coefficient: aero.lift_coefficient,
+                drag_coefficient: aero.drag_coefficient,
+                drag_force: aero.drag_force,
+                lift_to_drag: aero.lift_force / aero.drag_force.max(1e-6),
+                power_to_overcome_drag,
+                propeller_efficiency: propulsion.efficiency,
+                power_required: propulsion.shaft_power + aero.flapping_power,
+                stalled: aero.lift_force < weight_force * 0.999,
+                propeller_limited: propulsion.limited,
+            }
+        })
+        .collect();
+
+    let flying = || points.iter().filter(|point| !point.stalled && !point.propeller_limited);
+
+    let min_power_point = flying().min_by(|a, b| a.power_required.total_cmp(&b.power_required));
+    let max_ld_point = flying().max_by(|a, b| a.lift_to_drag.total_cmp(&b.lift_to_drag));
+
+    let pilot_curve = PowerDurationCurve::from_params(params);
+    let pilot_sustained_power = params.pilot_shaft_power(pilot_curve.critical_power);
+    let pilot_burst_power = params.pilot_shaft_power(pilot_curve.burst_power());
+    let motor_shaft_power = params.motor_shaft_power();
+    let sustained_power_available = pilot_sustained_power + motor_shaft_power;
+
+    // Power available meets power required between the fastest feasible point and the one after it
+    let fastest = points.iter().rposition(|point| {
+        !point.stalled && !point.propeller_limited && point.power_required <= sustained_power_available
+    });
+    let v_max_above_sweep = fastest == Some(points.len() - 1);
+    let v_max = fastest.filter(|_| !v_max_above_sweep).map(|index| {
+        let (below, above) = (&points[index], &points[index + 1]);
+        let rise = above.power_required - below.power_required;
+        if rise.is_finite() && rise > 0.0 {
+            below.airspeed + (sustained_power_available - below.power_required) / rise * (above.airspeed - below.airspeed)
+        } else {
+            below.airspeed
+        }
+    });
+
+    SpeedSweep {
+        stall_speed,
+        v_min_power: min_power_point.map(|point| point.airspeed),
+        min_power_required: min_power_point.map_or(f32::INFINITY, |point| point.power_required),
+        v_max_lift_to_drag: max_ld_point.map(|point| point.airspeed),
+        max_lift_to_drag: max_ld_point.map_or(0.0, |point| point.lift_to_drag),
+        v_max,
+        v_max_above_sweep,
+        pilot_sustained_power,
+        pilot_burst_power,
+        motor_shaft_power,
+        sustained_power_available,
+        points,
+    }
+}
@@ -0,0 +1,590 @@
+use serde::Serialize;
+
+use crate::airfoil::SectionPolar;
+use crate::atmosphere::Atmosphere;
+use crate::battery::{Battery, BatteryAnalysis};
+use crate::flapping::FlappingCycle;
+use crate::lift_curve::LiftCurve;
+use crate::naca::ThinAirfoilEstimate;
+use crate::multiplane::MunkInterference;
+use crate::params::{FlightParams, WingLayout};
+use crate::parasite::ParasiteDrag;
+use crate::pilot::{PilotAnalysis, PowerDurationCurve};
+use crate::planform::WingPlanform;
+use crate::propeller::{Propeller, PropellerPoint};
+use crate::structure::{calculate_structural_properties, StructuralAnalysis};
+use crate::vortex_lattice::SpanLoading;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum FlightPhase {
+    OnGround,
+    Takeoff,
+    InFlight,
+    Landing,
+}
+
+// Takeoff and landing reference speeds as multiples of the stall speed
+pub const ROTATION_SPEED_FACTOR: f32 = 1.2;
+pub const APPROACH_SPEED_FACTOR: f32 = 1.3;
+pub const TOUCHDOWN_SPEED_FACTOR: f32 = 1.15;
+pub const GROUND_LIFT_COEFFICIENT: f32 = 0.4;  // Wing at ground-roll attitude
+pub const OBSTACLE_HEIGHT: f32 = 15.0;  // m, standard screen height for field lengths
+const TRANSITION_LOAD_FACTOR: f32 = 1.2;  // Pull-up after liftoff and landing flare
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TakeoffAnalysis {
+    pub liftoff_speed: f32,  // m/s airspeed
+    pub ground_roll: f32,  // m
+    pub time_to_liftoff: f32,  // s
+    pub climb_angle: f32,  // rad, steady climb at liftoff speed
+    pub air_distance: f32,  // m from liftoff to the obstacle
+    pub obstacle_distance: f32,  // m, ground roll + air distance
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LandingAnalysis {
+    pub approach_speed: f32,  // m/s
+    pub approach_angle: f32,  // rad below the horizon, power-off glide
+    pub approach_sink_rate: f32,  // m/s
+    pub flare_height: f32,  // m
+    pub touchdown_speed: f32,  // m/s
+    pub air_distance: f32,  // m from the obstacle to touchdown
+    pub ground_roll: f32,  // m from touchdown to stop
+    pub landing_distance: f32,  // m, air distance + ground roll
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FlightAnalysis {
+    // Basic properties (independent of flight state)
+    pub total_mass: f32,
+    pub weight_force: f32,
+    pub wing_area: f32,
+    pub planform: WingPlanform,  // One wing
+    pub wing_layout: WingLayout,
+    pub wing_loading: f32,
+    pub stall_speed: f32,
+    pub airfoil: SectionPolar,
+    pub thin_airfoil: Option<ThinAirfoilEstimate>,  // Generated NACA sections only
+    pub lift_curve: LiftCurve,
+    pub span_loading: SpanLoading,
+    pub munk_interference: MunkInterference,
+    pub parasite_drag: ParasiteDrag,  // At the current airspeed
+    
+    // Flight-dependent values (only valid when airborne)
+    pub flight_phase: FlightPhase,
+    pub effective_airspeed: f32,
+    pub dynamic_pressure: f32,
+    pub current_lift_coefficient: f32,
+    pub current_drag_coefficient: f32,
+    pub angle_of_attack: f32,  // deg, wing
+    pub stall_margin: f32,  // deg below the stall angle
+    pub induced_drag_coefficient: f32,
+    pub span_efficiency: f32,  // At the current CL
+    
+    // Forces (calculated based on flight conditions)
+    pub lift_force: f32,
+    pub drag_force: f32,
+    
+    // Power requirements
+    pub power_to_overcome_drag: f32,
+    pub power_for_parasite_drag: f32,  // Share of the drag power spent on the airframe
+    pub propeller: PropellerPoint,
+    pub flapping: FlappingCycle,
+    pub power_for_flapping: f32,
+    pub power_for_climb: f32,
+    pub total_power_required: f32,
+    pub power_loading: f32,
+    
+    // Flight capabilities
+    pub can_takeoff: bool,
+    pub can_sustain_level_flight: bool,
+    pub can_climb: f32,
+    
+    pub motor_flight_time: f32,  // min
+    pub battery: BatteryAnalysis,
+    pub pilot: PilotAnalysis,
+    pub takeoff_distance: f32,
+    pub takeoff: TakeoffAnalysis,
+    pub landing: LandingAnalysis,
+    
+    pub structural: StructuralAnalysis,
+    pub atmosphere: Atmosphere,
+    
+    pub reynolds_number: f32,
+    pub flutter_margin: f32,
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct AeroState {
+    pub dynamic_pressure: f32,
+    pub angle_of_attack: f32,  // deg
+    pub lift_coefficient: f32,
+    pub drag_coefficient: f32,
+    pub lift_force: f32,
+    pub drag_force: f32,  // Airframe and fixed-wing drag
+    pub flapping_thrust: f32,  // Stroke-averaged, offsets part of the drag
+    pub flapping_power: f32,  // Aerodynamic and inertial
+}
+
+// Induced drag from the vortex-lattice span loading, including interference between wing pairs
+pub fn induced_drag_coefficient(params: &FlightParams, cl: f32) -> f32 {
+    SpanLoading::from_params(params).induced_drag_coefficient(cl)
+}
+
+// Steady level flight at the given airspeed: the wing pitches up the lift curve until L = W,
+// or holds the stall angle when even CLmax is not enough
+pub fn level_flight_aero(params: &FlightParams, air_density: f32, weight_force: f32, airspeed: f32) -> AeroState {
+    level_flight(params, air_density, weight_force, airspeed).0
+}
+
+// Level flight together with the flapping cycle that holds it; the stroke-averaged lift factor
+// is refined a few times as the angle of attack settles
+pub fn level_flight(params: &FlightParams, air_density: f32, weight_force: f32, airspeed: f32) -> (AeroState, FlappingCycle) {
+    let wing_area = params.wing_area();
+    let q = 0.5 * air_density * airspeed.powi(2);
+    let section = SectionPolar::from_params(params);
+    let lift_curve = LiftCurve::from_section(params, &section);
+    let wing_mass = if params.flapping_frequency > 0.1 { calculate_structural_properties(params).wing_mass } else { 0.0 };
+    
+    // Required CL for level flight: L = W
+    let required_cl = weight_force / (q * wing_area);
+    let mut angle_of_attack = lift_curve.angle_for_lift(required_cl).unwrap_or(lift_curve.stall_angle);
+    let mut flapping = FlappingCycle::at_airspeed(params, &lift_curve, &section, wing_mass, air_density, airspeed, angle_of_attack);
+    for _ in 0..3 {
+        if params.flapping_frequency <= 0.1 {
+            break;
+        }
+        let lift_factor = flapping.lift_factor.clamp(0.25, 4.0);
+        angle_of_attack = lift_curve.angle_for_lift(required_cl / lift_factor).unwrap_or(lift_curve.stall_angle);
+        flapping = FlappingCycle::at_airspeed(params, &lift_curve, &section, wing_mass, air_density, airspeed, angle_of_attack);
+    }
+    let wing_cl = lift_curve.lift_coefficient(angle_of_attack);
+    // Stroke-averaged; if we can't generate enough lift, we're in a dive/descending flight
+    let cl = wing_cl * flapping.lift_factor;
+    let lift = cl * q * wing_area;
+    
+    let cd = section.profile_drag(wing_cl) + induced_drag_coefficient(params, cl)
+        + ParasiteDrag::at_airspeed(params, airspeed).drag_coefficient;
+    let drag = cd * q * wing_area;
+    
+    let aero = AeroState {
+        dynamic_pressure: q,
+        angle_of_attack,
+        lift_coefficient: cl,
+        drag_coefficient: cd,
+        lift_force: lift,
+        drag_force: drag,
+        flapping_thrust: flapping.thrust,
+        flapping_power: flapping.total_power(),
+    };
+    (aero, flapping)
+}
+
+// Propeller thrust from the shaft power
+pub fn propulsive_thrust(params: &FlightParams, air_density: f32, shaft_power: f32, airspeed: f32) -> f32 {
+    Propeller::from_params(params).thrust_for_power(air_density, shaft_power, airspeed).thrust
+}
+
+// Drag of the wing at ground-roll attitude plus the airframe
+pub fn ground_roll_drag_coefficient(params: &FlightParams) -> f32 {
+    let section = SectionPolar::from_params(params);
+    let cl = GROUND_LIFT_COEFFICIENT.min(section.cl_max);
+    section.profile_drag(cl) + induced_drag_coefficient(params, cl) + ParasiteDrag::from_params(params).drag_coefficient
+}
+
+// Wing lift coefficient while rolling on the wheels
+pub fn ground_roll_lift_coefficient(params: &FlightParams) -> f32 {
+    GROUND_LIFT_COEFFICIENT.min(SectionPolar::from_params(params).cl_max)
+}
+
+// Power-off approach at 1.3 Vs, circular-arc flare to touchdown at 1.15 Vs, then a braked ground roll
+pub fn calculate_landing_performance(params: &FlightParams, air_density: f32, weight_force: f32, stall_speed: f32) -> LandingAnalysis {
+    let approach_speed = stall_speed * APPROACH_SPEED_FACTOR;
+    let touchdown_speed = stall_speed * TOUCHDOWN_SPEED_FACTOR;
+    
+    // Glide angle from the level-flight polar: tan γ = D/L
+    let aero = level_flight_aero(params, air_density, weight_force, approach_speed);
+    let approach_angle = (aero.drag_force / aero.lift_force.max(1e-6)).atan();
+    let approach_sink_rate = approach_speed * approach_angle.sin();
+    
+    // Flare radius R = V²/(g(n-1)) at the mean flare speed
+    let flare_speed = 0.5 * (approach_speed + touchdown_speed);
+    let flare_radius = flare_speed.powi(2) / (9.81 * (TRANSITION_LOAD_FACTOR - 1.0));
+    let flare_height = (flare_radius * (1.0 - approach_angle.cos())).min(OBSTACLE_HEIGHT);
+    let flare_distance = flare_radius * approach_angle.sin();
+    
+    // Wind stretches or shortens the ground track
+    let ground_speed_ratio = ((approach_speed - params.headwind()) / approach_speed.max(0.1)).max(0.0);
+    let air_distance = ((OBSTACLE_HEIGHT - flare_height) / approach_angle.tan().max(1e-3) + flare_distance) * ground_speed_ratio;
+    let ground_roll = landing_ground_roll(params, air_density, weight_force, touchdown_speed);
+    
+    LandingAnalysis {
+        approach_speed,
+        approach_angle,
+        approach_sink_rate,
+        flare_height,
+        touchdown_speed,
+        air_distance,
+        ground_roll,
+        landing_distance: air_distance + ground_roll,
+    }
+}
+
+// Decelerate from touchdown with brakes on the wheel load plus aerodynamic drag
+fn landing_ground_roll(params: &FlightParams, air_density: f32, weight_force: f32, touchdown_speed: f32) -> f32 {
+    let mass = weight_force / 9.81;
+    let wing_area = params.wing_area();
+    let cl = ground_roll_lift_coefficient(params);
+    let cd = ground_roll_drag_coefficient(params);
+    let friction = params.braking_friction.max(params.rolling_friction()).max(0.0);
+    let slope = params.runway_slope.atan();
+    
+    let dt = 0.01;  // s
+    let mut ground_speed = touchdown_speed - params.headwind();
+    let mut distance = 0.0;
+    let mut time = 0.0;
+    while ground_speed > 0.0 {
+        if time > 600.0 {
+            return f32::INFINITY;  // Barely decelerating, e.g. braking down a steep slope
+        }
+        let airspeed = ground_speed + params.headwind();
+        let q = 0.5 * air_density * airspeed.powi(2);
+        let normal_force = (weight_force * slope.cos() - cl * q * wing_area).max(0.0);
+        let drag = cd * q * wing_area * airspeed.signum();
+        let deceleration = (friction * normal_force + drag + weight_force * slope.sin()) / mass;
+        if deceleration <= 0.0 {
+            return f32::INFINITY;  // A strong tailwind keeps pushing the aircraft along
+        }
+        distance += ground_speed * dt;
+        ground_speed -= deceleration * dt;
+        time += dt;
+    }
+    distance
+}
+
+// Integrated ground roll to 1.2 Vs, then a pull-up arc and steady climb over the 15 m obstacle;
+// the wings are held still on the wheels and flap from rotation onwards
+pub fn calculate_takeoff_performance(params: &FlightParams, air_density: f32, weight_force: f32, stall_speed: f32, shaft_power: f32) -> TakeoffAnalysis {
+    let mass = weight_force / 9.81;
+    let wing_area = params.wing_area();
+    let cl = ground_roll_lift_coefficient(params);
+    let cd = ground_roll_drag_coefficient(params);
+    let friction = params.rolling_friction();
+    let slope = params.runway_slope.atan();
+    let headwind = params.headwind();
+    let liftoff_speed = stall_speed * ROTATION_SPEED_FACTOR;
+    let propeller = Propeller::from_params(params);
+    
+    // Shaft power is constant during the roll, so tabulate thrust against airspeed once
+    let table_points = 12;
+    let thrust_table: Vec<f32> = (0..=table_points)
+        .map(|i| propeller.thrust_for_power(air_density, shaft_power, liftoff_speed * i as f32 / table_points as f32).thrust)
+        .collect();
+    let thrust_at = |airspeed: f32| {
+        let position = (airspeed.max(0.0) / liftoff_speed.max(1e-3) * table_points as f32).min(table_points as f32);
+        let index = (position as usize).min(table_points - 1);
+        let fraction = position - index as f32;
+        thrust_table[index] + (thrust_table[index + 1] - thrust_table[index]) * fraction
+    };
+    
+    let mut takeoff = TakeoffAnalysis {
+        liftoff_speed,
+        ground_roll: f32::INFINITY,
+        time_to_liftoff: f32::INFINITY,
+        climb_angle: 0.0,
+        air_distance: f32::INFINITY,
+        obstacle_distance: f32::INFINITY,
+    };
+    
+    // Thrust falls and drag grows with speed while lift progressively unloads the wheels
+    let dt = 0.01;  // s
+    let mut ground_speed = 0.0f32;
+    let mut distance = 0.0;
+    let mut time = 0.0;
+    loop {
+        let airspeed = ground_speed + headwind;
+        if airspeed >= liftoff_speed {
+            break;
+        }
+        let q = 0.5 * air_density * airspeed.powi(2);
+        let lift = cl * q * wing_area;
+        let drag = cd * q * wing_area * airspeed.signum();
+        let thrust = thrust_at(airspeed);
+        let normal_force = (weight_force * slope.cos() - lift).max(0.0);
+        let acceleration = (thrust - drag - friction * normal_force - weight_force * slope.sin()) / mass;
+        if acceleration <= 0.0 || time > 600.0 {
+            return takeoff;  // Stuck below liftoff speed
+        }
+        ground_speed += acceleration * dt;
+        distance += ground_speed * dt;
+        time += dt;
+    }
+    takeoff.ground_roll = distance;
+    takeoff.time_to_liftoff = time;
+    
+    // Steady climb gradient at liftoff speed: sin γ = (T - D)/W, with the flapping stroke taking its
+    // share of the shaft power and adding its thrust when the power pays for the whole stroke
+    let aero = level_flight_aero(params, air_density, weight_force, liftoff_speed);
+    let thrust = if shaft_power >= aero.flapping_power {
+        propeller.thrust_for_power(air_density, shaft_power - aero.flapping_power, liftoff_speed).thrust + aero.flapping_thrust
+    } else {
+        propeller.thrust_for_power(air_density, shaft_power, liftoff_speed).thrust
+    };
+    let climb_angle = ((thrust - aero.drag_force) / weight_force).clamp(-1.0, 1.0).asin();
+    takeoff.climb_angle = climb_angle;
+    if climb_angle <= 0.0 {
+        return takeoff;
+    }
+    
+    // Pull-up arc R = V²/(g(n-1)); the obstacle may be cleared before the arc ends
+    let radius = liftoff_speed.powi(2) / (9.81 * (TRANSITION_LOAD_FACTOR - 1.0));
+    let transition_height = radius * (1.0 - climb_angle.cos());
+    let air_path = if transition_height >= OBSTACLE_HEIGHT {
+        (radius.powi(2) - (radius - OBSTACLE_HEIGHT).powi(2)).sqrt()
+    } else {
+        radius * climb_angle.sin() + (OBSTACLE_HEIGHT - transition_height) / climb_angle.tan()
+    };
+    let ground_speed_ratio = ((liftoff_speed - headwind) / liftoff_speed).max(0.0);
+    takeoff.air_distance = air_path * ground_speed_ratio;
+    takeoff.obstacle_distance = takeoff.ground_roll + takeoff.air_distance;
+    takeoff
+}
+
+pub fn calculate_comprehensive_flight_analysis(params: &FlightParams) -> FlightAnalysis {
+    let structural = calculate_structural_properties(params);
+    let atmosphere = params.atmosphere();
+    
+    // Basic properties that don't depend on flight state
+    let total_mass = params.pilot_mass + structural.total_structural_mass;
+    let weight_force = total_mass * 9.81;
+    let wing_area = params.wing_area();
+    let wing_loading = weight_force / wing_area;
+    
+    // Calculate stall speed (minimum speed needed for lift = weight)
+    let airfoil = SectionPolar::from_params(params);
+    let lift_curve = LiftCurve::from_section(params, &airfoil);
+    let stall_speed = (2.0 * weight_force / (atmosphere.density * wing_area * airfoil.cl_max)).sqrt();
+    
+    // Determine flight phase based on speed and power; the approach is only ever flown on request
+    let effective_airspeed = (params.forward_speed - params.wind_speed).max(0.0);
+    let pilot_curve = PowerDurationCurve::from_params(params);
+    let available_power = params.pilot_shaft_power(pilot_curve.burst_power()) + params.motor_shaft_power();
+    
+    let flight_phase = if effective_airspeed < 1.0 {
+        FlightPhase::OnGround
+    } else if params.on_approach && effective_airspeed >= stall_speed {
+        FlightPhase::Landing
+    } else if effective_airspeed < stall_speed * 1.1 && available_power > 1000.0 {
+        FlightPhase::Takeoff
+    } else if effective_airspeed >= stall_speed {
+        FlightPhase::InFlight
+    } else {
+        FlightPhase::OnGround
+    };
+    
+    // Calculate flight-dependent values
+    let (dynamic_pressure, angle_of_attack, current_lift_coefficient, current_drag_coefficient, lift_force, drag_force, flapping) = 
+    match flight_phase {
+        // Sitting on the wheels at the ground-roll attitude
+        FlightPhase::OnGround => {
+            let angle = lift_curve.angle_for_lift(ground_roll_lift_coefficient(params)).unwrap_or(lift_curve.zero_lift_angle);
+            (0.0, angle, 0.0, 0.0, 0.0, 0.0, FlappingCycle { lift_factor: 1.0, ..FlappingCycle::default() })
+        },
+        
+        FlightPhase::Takeoff => {
+            let q = 0.5 * atmosphere.density * effective_airspeed.powi(2);
+            
+            // During takeoff the wing is rotated to the stall angle and flaps at full stroke
+            let flapping = FlappingCycle::at_airspeed(params, &lift_curve, &airfoil, structural.wing_mass,
+                atmosphere.density, effective_airspeed, lift_curve.stall_angle);
+            let wing_cl = lift_curve.lift_coefficient(lift_curve.stall_angle);
+            let cl = wing_cl * flapping.lift_factor;
+            let lift = cl * q * wing_area;
+            
+            let cd = airfoil.profile_drag(wing_cl) + induced_drag_coefficient(params, cl)
+                + ParasiteDrag::at_airspeed(params, effective_airspeed).drag_coefficient;
+            let drag = cd * q * wing_area;
+            
+            (q, lift_curve.stall_angle, cl, cd, lift, drag, flapping)
+        },
+        
+        FlightPhase::InFlight => {
+            let (aero, flapping) = level_flight(params, atmosphere.density, weight_force, effective_airspeed);
+            (aero.dynamic_pressure, aero.angle_of_attack, aero.lift_coefficient, aero.drag_coefficient, aero.lift_force, aero.drag_force, flapping)
+        },
+        
+        // Power-off glide with the wings held still: L = W cos γ along a path of tan γ = D/L
+        FlightPhase::Landing => {
+            let q = 0.5 * atmosphere.density * effective_airspeed.powi(2);
+            let mut glide_angle: f32 = 0.0;
+            let (mut angle, mut cl, mut cd) = (lift_curve.zero_lift_angle, 0.0, 0.0);
+            for _ in 0..3 {
+                angle = lift_curve.angle_for_lift(weight_force * glide_angle.cos() / (q * wing_area)).unwrap_or(lift_curve.stall_angle);
+                cl = lift_curve.lift_coefficient(angle);
+                cd = airfoil.profile_drag(cl) + induced_drag_coefficient(params, cl)
+                    + ParasiteDrag::at_airspeed(params, effective_airspeed).drag_coefficient;
+                glide_angle = (cd / cl.max(1e-6)).atan();
+            }
+            
+            (q, angle, cl, cd, cl * q * wing_area, cd * q * wing_area, FlappingCycle { lift_factor: 1.0, ..FlappingCycle::default() })
+        },
+    };
+    let stall_margin = lift_curve.stall_margin(angle_of_attack);
+    let span_loading = SpanLoading::from_params(params);
+    let span_efficiency = span_loading.span_efficiency(current_lift_coefficient);
+    
+    // Power calculations
+    let power_to_overcome_drag = if flight_phase != FlightPhase::OnGround {
+        drag_force * effective_airspeed
+    } else {
+        0.0
+    };
+    
+    let parasite_drag = ParasiteDrag::at_airspeed(params, effective_airspeed);
+    let power_for_parasite_drag = if flight_phase != FlightPhase::OnGround {
+        parasite_drag.drag_coefficient * dynamic_pressure * wing_area * effective_airspeed
+    } else {
+        0.0
+    };
+    
+    // Propeller operating point delivering the thrust the flapping wings leave to it
+    let propeller = if matches!(flight_phase, FlightPhase::Takeoff | FlightPhase::InFlight) {
+        let required_thrust = (drag_force - flapping.thrust).max(0.0);
+        Propeller::from_params(params).power_for_thrust(atmosphere.density, required_thrust, effective_airspeed)
+    } else {
+        PropellerPoint::default()
+    };
+    
+    let power_for_flapping = flapping.total_power();
+    
+    let power_for_climb = if flight_phase == FlightPhase::InFlight {
+        // Power for climb = Weight × Climb_Rate
+        // Climb rate determined by excess lift converted to vertical velocity
+        let excess_lift = (lift_force - weight_force).max(0.0);
+        let climb_rate = excess_lift / weight_force * effective_airspeed * 0.1;  // Small angle approximation
+        weight_force * climb_rate
+    } else {
+        0.0
+    };
+    
+    // Shaft power: the propeller turns only part of it into useful thrust power
+    let total_power_required = propeller.shaft_power + power_for_flapping + power_for_climb;
+    let power_loading = if total_power_required > 0.0 { 
+        total_power_required / weight_force 
+    } else { 
+        0.0 
+    };
+    
+    // Flight capabilities
+    let can_takeoff = available_power > total_power_required * 1.3 && 
+                     structural.structural_feasible &&
+                     effective_airspeed > stall_speed * 0.8;
+    
+    // Sustained flight includes motor assistance
+    let sustained_power_available = params.pilot_shaft_power(pilot_curve.critical_power) + params.motor_shaft_power();
+    let can_sustain_level_flight = sustained_power_available > total_power_required && 
+                                  !propeller.limited &&
+                                  effective_airspeed > stall_speed &&
+                                  structural.structural_feasible;
+    
+    // Climb rate calculation with proper power balance
+    let excess_power = sustained_power_available - total_power_required;
+    let can_climb = if flight_phase == FlightPhase::Landing {
+        -power_to_overcome_drag / weight_force  // Glide sink rate, V sin γ
+    } else if excess_power > 0.0 && propeller.thrust > 0.0 { 
+        excess_power * propeller.efficiency / weight_force  // Climb rate in m/s
+    } else if excess_power > 0.0 {
+        excess_power / weight_force  // Flapping carries the drag, the propeller is idle
+    } else { 
+        -1.0  // Descent rate if insufficient power
+    };
+    
+    // Battery endurance based on actual motor power draw, not rated power
+    let actual_motor_power_draw = if flight_phase != FlightPhase::OnGround {
+        params.motor_shaft_power().min(total_power_required - params.pilot_shaft_power(pilot_curve.critical_power).max(0.0))
+    } else {
+        0.0
+    };
+    let battery_power_draw = if params.motor_efficiency > 0.0 {
+        actual_motor_power_draw.max(0.0) / params.motor_efficiency
+    } else {
+        0.0
+    };
+    let battery = Battery::from_params(params).analyze(battery_power_draw);
+    let motor_flight_time = battery.endurance;
+    
+    // Pedal power in cruise, with and without the motor carrying its share
+    let pedal_power = |shaft_power: f32| if params.drivetrain_efficiency > 0.0 {
+        shaft_power.max(0.0) / params.drivetrain_efficiency
+    } else {
+        f32::INFINITY
+    };
+    let (assisted_pedal_power, unassisted_pedal_power) = if flight_phase != FlightPhase::OnGround {
+        (pedal_power(total_power_required - actual_motor_power_draw.max(0.0)), pedal_power(total_power_required))
+    } else {
+        (0.0, 0.0)
+    };
+    let pilot = pilot_curve.analyze(assisted_pedal_power, unassisted_pedal_power, motor_flight_time * 60.0);
+    
+    // Takeoff at full burst power
+    let takeoff = calculate_takeoff_performance(params, atmosphere.density, weight_force, stall_speed, available_power);
+    let takeoff_distance = takeoff.ground_roll;
+    
+    let landing = calculate_landing_performance(params, atmosphere.density, weight_force, stall_speed);
+    
+    let chord_length = params.planform().mean_aerodynamic_chord();
+    let reynolds_number = if effective_airspeed > 0.0 { 
+        effective_airspeed * chord_length / atmosphere.kinematic_viscosity
+    } else { 
+        0.0 
+    };
+    let flutter_margin = structural.critical_flutter_speed / effective_airspeed.max(1.0);
+    
+    FlightAnalysis {
+        total_mass,
+        weight_force,
+        wing_area,
+        planform: params.planform(),
+        wing_layout: params.wing_layout,
+        wing_loading,
+        stall_speed,
+        airfoil,
+        thin_airfoil: params.airfoil.naca().map(|naca| naca.thin_airfoil_estimate()),
+        lift_curve,
+        induced_drag_coefficient: span_loading.induced_drag_coefficient(current_lift_coefficient),
+        munk_interference: MunkInterference::from_planform(&span_loading.planform),
+        parasite_drag,
+        span_loading,
+        flight_phase,
+        effective_airspeed,
+        dynamic_pressure,
+        current_lift_coefficient,
+        current_drag_coefficient,
+        angle_of_attack,
+        stall_margin,
+        span_efficiency,
+        lift_force,
+        drag_force,
+        power_to_overcome_drag,
+        power_for_parasite_drag,
+        propeller,
+        flapping,
+        power_for_flapping,
+        power_for_climb,
+        total_power_required,
+        power_loading,
+        can_takeoff,
+        can_sustain_level_flight,
+        can_climb,
+        motor_flight_time,
+        battery,
+        pilot,
+        takeoff_distance,
+        takeoff,
+        landing,
+        structural,
+        atmosphere,
+        reynolds_number,
+        flutter_margin,
+    }
+}
@@ -0,0 +1,158 @@
+use serde::Serialize;
+
+use crate::params::{FlightParams, PilotProfile};
+
+// The burst rating is the power a pilot can hold for about a minute
+pub const BURST_DURATION: f32 = 60.0;  // s
+
+// Beyond roughly half an hour glycogen depletion pulls the critical power down
+const ENDURANCE_ONSET: f32 = 1800.0;  // s
+const CUSTOM_ENDURANCE_DECLINE: f32 = 0.07;  // Fraction of CP lost per e-fold of duration
+
+// Longest effort the model is asked about; anything beyond counts as unlimited
+const MAX_DURATION: f32 = 86400.0;  // s
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PowerDurationCurve {
+    pub critical_power: f32,  // W, the asymptote of the hyperbola
+    pub anaerobic_capacity: f32,  // J of work above critical power (W')
+    pub peak_power: f32,  // W, short sprint ceiling
+    pub endurance_decline: f32,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PilotAnalysis {
+    pub critical_power: f32,  // W
+    pub anaerobic_capacity: f32,  // J
+    pub burst_power: f32,  // W over BURST_DURATION
+    pub power_required: f32,  // W at the pedals in cruise, after any motor assistance
+    pub endurance: f32,  // min at the cruise pedal power
+    pub max_flight_duration: f32,  // min, including any motor assistance while the battery lasts
+}
+
+impl PilotProfile {
+    // Typical values for a 70-80 kg rider; None leaves the sustained/burst ratings in charge
+    pub fn power_duration_curve(&self) -> Option<PowerDurationCurve> {
+        match self {
+            PilotProfile::Custom => None,
+            PilotProfile::Recreational => Some(PowerDurationCurve {
+                critical_power: 150.0,
+                anaerobic_capacity: 12000.0,
+                peak_power: 700.0,
+                endurance_decline: 0.10,
+            }),
+            PilotProfile::TrainedCyclist => Some(PowerDurationCurve {
+                critical_power: 280.0,
+                anaerobic_capacity: 20000.0,
+                peak_power: 1100.0,
+                endurance_decline: 0.07,
+            }),
+            // Record human-powered aircraft pilots (Gossamer Albatross, Daedalus)
+            PilotProfile::Elite => Some(PowerDurationCurve {
+                critical_power: 340.0,
+                anaerobic_capacity: 22000.0,
+                peak_power: 1300.0,
+                endurance_decline: 0.05,
+            }),
+        }
+    }
+}
+
+impl PowerDurationCurve {
+    pub fn from_params(params: &FlightParams) -> Self {
+        params.pilot_profile.power_duration_curve().unwrap_or_else(|| {
+            // Sustained is taken as critical power and burst as the one-minute power
+            let critical_power = params.pilot_power_sustained.max(0.0);
+            let peak_power = params.pilot_power_burst.max(critical_power);
+            Self {
+                critical_power,
+                anaerobic_capacity: (peak_power - critical_power) * BURST_DURATION,
+                peak_power,
+                endurance_decline: CUSTOM_ENDURANCE_DECLINE,
+            }
+        })
+    }
+
+    // Critical power after `elapsed` seconds of continuous effort
+    pub fn critical_power_at(&self, elapsed: f32) -> f32 {
+        if elapsed <= ENDURANCE_ONSET {
+            return self.critical_power;
+        }
+        let decline = self.endurance_decline * (elapsed / ENDURANCE_ONSET).ln();
+        self.critical_power * (1.0 - decline).max(0.0)
+    }
+
+    // P(t) = CP + W'/t, capped by the sprint ceiling
+    pub fn power_for_duration(&self, duration: f32) -> f32 {
+        let duration = duration.max(1.0);
+        (self.critical_power_at(duration) + self.anaerobic_capacity / duration).min(self.peak_power)
+    }
+
+    pub fn burst_power(&self) -> f32 {
+        self.power_for_duration(BURST_DURATION)
+    }
+
+    // Seconds a fresh pilot can hold `power`
+    pub fn time_to_exhaustion(&self, power: f32) -> f32 {
+        self.time_to_exhaustion_after(power, 0.0, self.anaerobic_capacity)
+    }
+
+    // Seconds `power` can be held after `elapsed` seconds of effort with `balance` J of W' left
+    pub fn time_to_exhaustion_after(&self, power: f32, elapsed: f32, balance: f32) -> f32 {
+        if power <= 0.0 {
+            return f32::INFINITY;
+        }
+        if power > self.peak_power {
+            return 0.0;
+        }
+        let sustainable = |duration: f32| self.critical_power_at(elapsed + duration) + balance.max(0.0) / duration;
+        if sustainable(MAX_DURATION) >= power {
+            return f32::INFINITY;
+        }
+
+        // Available power falls monotonically with duration; bisect in log time
+        let (mut low, mut high) = (0.0_f32, MAX_DURATION.ln());
+        for _ in 0..40 {
+            let mid = 0.5 * (low + high);
+            if sustainable(mid.exp()) >= power {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low.exp()
+    }
+
+    // Skiba W' balance: drains above critical power, recovers exponentially below it
+    pub fn update_balance(&self, balance: f32, power: f32, elapsed: f32, dt: f32) -> f32 {
+        let critical_power = self.critical_power_at(elapsed);
+        if power > critical_power {
+            (balance - (power - critical_power) * dt).max(0.0)
+        } else {
+            let time_constant = 546.0 * (-0.01 * (critical_power - power)).exp() + 316.0;  // s
+            balance + (self.anaerobic_capacity - balance) * (1.0 - (-dt / time_constant).exp())
+        }
+    }
+
+    // `motor_endurance` is how long the motor can take its share, in seconds
+    pub fn analyze(&self, assisted_power: f32, unassisted_power: f32, motor_endurance: f32) -> PilotAnalysis {
+        let endurance = self.time_to_exhaustion(assisted_power);
+        let max_flight_duration = if endurance <= motor_endurance || assisted_power >= unassisted_power {
+            endurance
+        } else {
+            // Whatever W' the assisted leg used is gone when the battery runs out
+            let spent = (assisted_power - self.critical_power).max(0.0) * motor_endurance;
+            let balance = self.anaerobic_capacity - spent;
+            motor_endurance + self.time_to_exhaustion_after(unassisted_power, motor_endurance, balance)
+        };
+
+        PilotAnalysis {
+            critical_power: self.critical_power,
+            anaerobic_capacity: self.anaerobic_capacity,
+            burst_power: self.burst_power(),
+            power_required: assisted_power,
+            endurance: endurance / 60.0,
+            max_flight_duration: max_flight_duration / 60.0,
+        }
+    }
+}
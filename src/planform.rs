@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::params::FlightParams;
+
+// One trapezoidal piece of a wing, listed from the root outwards
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WingPanel {
+    pub span: f32,  // m, root to tip of the panel, before sweep
+    pub root_chord: f32,  // m
+    pub tip_chord: f32,  // m
+    pub sweep: f32,  // deg, quarter-chord line
+    pub dihedral: f32,  // deg
+    pub twist: f32,  // deg of washout added between the panel root and tip
+}
+
+// One wing from root to tip; the wings of a pair mirror each other about the centreline
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct WingPlanform {
+    pub panels: Vec<WingPanel>,
+}
+
+// Position of one spanwise station on a wing, from the root
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlanformStation {
+    pub y: f32,  // m, projected
+    pub x: f32,  // m of quarter-chord sweepback
+    pub z: f32,  // m of dihedral rise
+    pub chord: f32,  // m
+    pub washout: f32,  // deg
+    pub dihedral: f32,  // deg, local panel
+}
+
+impl WingPanel {
+    pub fn area(&self) -> f32 {
+        0.5 * (self.root_chord + self.tip_chord) * self.span
+    }
+
+    pub fn projected_span(&self) -> f32 {
+        self.span * self.dihedral.to_radians().cos()
+    }
+
+    pub fn taper_ratio(&self) -> f32 {
+        self.tip_chord / self.root_chord.max(1e-6)
+    }
+}
+
+impl WingPlanform {
+    // The panel list when one is given, otherwise a single straight-tapered panel from the sliders
+    pub fn from_params(params: &FlightParams) -> Self {
+        if !params.wing_panels.is_empty() {
+            return Self { panels: params.wing_panels.clone() };
+        }
+        let taper = params.wing_taper_ratio.clamp(0.05, 1.0);
+        let root_chord = 2.0 * params.wing_chord / (1.0 + taper);
+        Self {
+            panels: vec![WingPanel {
+                span: params.wing_span,
+                root_chord,
+                tip_chord: taper * root_chord,
+                sweep: params.wing_sweep,
+                dihedral: params.wing_dihedral,
+                twist: params.wing_twist,
+            }],
+        }
+    }
+
+    // Same shape with every length multiplied by the factor
+    pub fn scaled(&self, factor: f32) -> Self {
+        let panels = self
+            .panels
+            .iter()
+            .map(|panel| WingPanel {
+                span: panel.span * factor,
+                root_chord: panel.root_chord * factor,
+                tip_chord: panel.tip_chord * factor,
+                ..panel.clone()
+            })
+            .collect();
+        Self { panels }
+    }
+
+    // Root to tip along the panels
+    pub fn span(&self) -> f32 {
+        self.panels.iter().map(|panel| panel.span).sum()
+    }
+
+    pub fn projected_span(&self) -> f32 {
+        self.panels.iter().map(WingPanel::projected_span).sum()
+    }
+
+    // Length of the swept spar from root to tip
+    pub fn spar_length(&self) -> f32 {
+        self.panels.iter().map(|panel| panel.span / panel.sweep.to_radians().cos().max(0.1)).sum()
+    }
+
+    pub fn area(&self) -> f32 {
+        self.panels.iter().map(WingPanel::area).sum()
+    }
+
+    pub fn root_chord(&self) -> f32 {
+        self.panels.first().map_or(0.0, |panel| panel.root_chord)
+    }
+
+    pub fn tip_chord(&self) -> f32 {
+        self.panels.last().map_or(0.0, |panel| panel.tip_chord)
+    }
+
+    pub fn mean_chord(&self) -> f32 {
+        self.area() / self.span().max(1e-6)
+    }
+
+    // MAC = ∫c² ds / ∫c ds, exact for straight-tapered panels
+    pub fn mean_aerodynamic_chord(&self) -> f32 {
+        let chord_squared: f32 = self
+            .panels
+            .iter()
+            .map(|panel| panel.span * (panel.root_chord.powi(2) + panel.root_chord * panel.tip_chord + panel.tip_chord.powi(2)) / 3.0)
+            .sum();
+        chord_squared / self.area().max(1e-6)
+    }
+
+    // Distance of the area centroid from the root along the panels, where the lift of a wing acts
+    pub fn centroid_span(&self) -> f32 {
+        let mut start = 0.0;
+        let mut moment = 0.0;
+        for panel in &self.panels {
+            let (root, tip) = (panel.root_chord, panel.tip_chord);
+            let centroid = panel.span * (root + 2.0 * tip) / (3.0 * (root + tip).max(1e-6));
+            moment += panel.area() * (start + centroid);
+            start += panel.span;
+        }
+        moment / self.area().max(1e-6)
+    }
+
+    // Station at a projected distance y from the root, clamped to the tip
+    pub fn station(&self, y: f32) -> PlanformStation {
+        let mut station = PlanformStation {
+            y: 0.0,
+            x: 0.0,
+            z: 0.0,
+            chord: self.root_chord(),
+            washout: 0.0,
+            dihedral: self.panels.first().map_or(0.0, |panel| panel.dihedral),
+        };
+        for (index, panel) in self.panels.iter().enumerate() {
+            let width = panel.projected_span();
+            let last = index + 1 == self.panels.len();
+            if y > station.y + width && !last {
+                station.x += panel.span * panel.sweep.to_radians().tan();
+                station.z += panel.span * panel.dihedral.to_radians().sin();
+                station.y += width;
+                station.washout += panel.twist;
+                continue;
+            }
+            let fraction = ((y - station.y) / width.max(1e-6)).clamp(0.0, 1.0);
+            let along = fraction * panel.span;
+            return PlanformStation {
+                y: station.y + fraction * width,
+                x: station.x + along * panel.sweep.to_radians().tan(),
+                z: station.z + along * panel.dihedral.to_radians().sin(),
+                chord: panel.root_chord + (panel.tip_chord - panel.root_chord) * fraction,
+                washout: station.washout + panel.twist * fraction,
+                dihedral: panel.dihedral,
+            };
+        }
+        station
+    }
+}
@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{file_stem, migrate_params, user_config_dir, ConfigError};
+use crate::materials::MaterialLibrary;
+use crate::params::FlightParams;
+
+// The original seven designs ship inside the binary so they are always available
+const BUILTIN_PRESETS: [&str; 7] = [
+    include_str!("../presets/default.toml"),
+    include_str!("../presets/ultralight_glider.toml"),
+    include_str!("../presets/powered_takeoff.toml"),
+    include_str!("../presets/sustained_flight.toml"),
+    include_str!("../presets/max_efficiency.toml"),
+    include_str!("../presets/minimal_weight.toml"),
+    include_str!("../presets/racing.toml"),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PresetSource {
+    BuiltIn,
+    User(PathBuf),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Preset {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub params: FlightParams,
+    #[serde(skip, default = "default_source")]
+    pub source: PresetSource,
+}
+
+fn default_source() -> PresetSource {
+    PresetSource::BuiltIn
+}
+
+impl Preset {
+    pub fn parse(text: &str, source: PresetSource, materials: &MaterialLibrary) -> Result<Self, ConfigError> {
+        let mut table: toml::Table = toml::from_str(text)?;
+        if let Some(toml::Value::Table(params)) = table.get_mut("params") {
+            migrate_params(params);
+            materials.expand_names(params)?;
+        }
+        let mut preset: Preset = toml::Value::Table(table).try_into()?;
+        preset.source = source;
+        Ok(preset)
+    }
+
+    pub fn load(path: &Path, materials: &MaterialLibrary) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text, PresetSource::User(path.to_path_buf()), materials)
+    }
+
+    pub fn is_builtin(&self) -> bool {
+        self.source == PresetSource::BuiltIn
+    }
+}
+
+pub fn user_preset_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("presets"))
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PresetLibrary {
+    pub presets: Vec<Preset>,
+    pub load_errors: Vec<String>,
+}
+
+impl PresetLibrary {
+    pub fn builtin() -> Self {
+        let materials = MaterialLibrary::builtin();
+        let presets = BUILTIN_PRESETS
+            .iter()
+            .map(|text| Preset::parse(text, PresetSource::BuiltIn, &materials).expect("built-in preset files are valid"))
+            .collect();
+        Self { presets, load_errors: Vec::new() }
+    }
+
+    // Built-ins followed by every *.toml preset in the user directory, which may name materials
+    // from the given library
+    pub fn load(materials: &MaterialLibrary) -> Self {
+        let mut library = Self::builtin();
+        if let Some(dir) = user_preset_dir() {
+            library.load_dir(&dir, materials);
+        }
+        library
+    }
+
+    pub fn load_dir(&mut self, dir: &Path, materials: &MaterialLibrary) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;  // No user presets yet
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            match Preset::load(&path, materials) {
+                Ok(preset) => self.insert(preset),
+                Err(err) => self.load_errors.push(format!("{}: {}", path.display(), err)),
+            }
+        }
+    }
+
+    // A preset with the same name replaces the existing entry
+    fn insert(&mut self, preset: Preset) {
+        match self.presets.iter_mut().find(|existing| existing.name == preset.name) {
+            Some(existing) => *existing = preset,
+            None => self.presets.push(preset),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Preset> {
+        self.presets.iter().find(|preset| preset.name == name)
+    }
+
+    pub fn save_user_preset(
+        &mut self,
+        name: &str,
+        description: &str,
+        params: &FlightParams,
+    ) -> Result<PathBuf, ConfigError> {
+        let dir = user_preset_dir().ok_or_else(|| {
+            ConfigError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no user configuration directory",
+            ))
+        })?;
+        self.save_preset_to(&dir, name, description, params)
+    }
+
+    pub fn save_preset_to(
+        &mut self,
+        dir: &Path,
+        name: &str,
+        description: &str,
+        params: &FlightParams,
+    ) -> Result<PathBuf, ConfigError> {
+        let path = dir.join(format!("{}.toml", file_stem(name, "preset")));
+        let preset = Preset {
+            name: name.trim().to_string(),
+            description: description.trim().to_string(),
+            params: params.clone(),
+            source: PresetSource::User(path.clone()),
+        };
+
+        fs::create_dir_all(dir)?;
+        fs::write(&path, toml::to_string_pretty(&preset)?)?;
+        self.insert(preset);
+        Ok(path)
+    }
+}
@@ -0,0 +1,200 @@
+use serde::Serialize;
+
+use crate::params::FlightParams;
+
+// Blade-element properties of a representative section at 75% radius
+const REFERENCE_RADIUS_FRACTION: f32 = 0.75;
+const SECTION_LIFT_SLOPE: f32 = 5.7;  // per rad, 2π reduced for finite blade aspect ratio
+const SECTION_CL_MAX: f32 = 1.2;
+const SECTION_CD_MIN: f32 = 0.012;
+const SECTION_DRAG_FACTOR: f32 = 0.015;  // CD = CDmin + k·CL²
+const POST_STALL_DRAG_SLOPE: f32 = 1.0;  // per rad of angle of attack beyond stall
+
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct PropellerPoint {
+    pub thrust: f32,  // N
+    pub shaft_power: f32,  // W absorbed at the propeller shaft
+    pub unused_power: f32,  // W offered beyond what the propeller absorbs at full RPM
+    pub efficiency: f32,  // T·V/P
+    pub rpm: f32,  // Shaft speed of this operating point
+    pub advance_ratio: f32,  // J = V/(nD)
+    pub induced_velocity: f32,  // m/s through the disk
+    pub blade_angle_of_attack: f32,  // rad at 75% radius
+    pub stalled: bool,  // Blades past the section stall angle, so the thrust costs extra profile drag
+    pub limited: bool,  // Full RPM is short of the thrust asked for; thrust and power are its limit
+}
+
+// Fixed-pitch propeller, throttled by turning it slower
+#[derive(Clone, Debug)]
+pub struct Propeller {
+    pub diameter: f32,  // m
+    pub pitch: f32,  // m of geometric advance per revolution
+    pub blade_count: u32,
+    pub blade_chord: f32,  // m at 75% radius
+    pub max_rpm: f32,
+}
+
+impl Propeller {
+    pub fn from_params(params: &FlightParams) -> Self {
+        Self {
+            diameter: params.propeller_diameter,
+            pitch: params.propeller_pitch,
+            blade_count: params.propeller_blades,
+            blade_chord: params.propeller_blade_chord,
+            max_rpm: params.propeller_rpm,
+        }
+    }
+
+    pub fn disk_area(&self) -> f32 {
+        std::f32::consts::PI * (0.5 * self.diameter).powi(2)
+    }
+
+    // Blade area over disk area, σ = Bc/(πR)
+    pub fn solidity(&self) -> f32 {
+        self.blade_count as f32 * self.blade_chord / (std::f32::consts::PI * 0.5 * self.diameter).max(1e-6)
+    }
+
+    pub fn advance_ratio(&self, airspeed: f32, rpm: f32) -> f32 {
+        airspeed / ((rpm / 60.0) * self.diameter).max(1e-3)
+    }
+
+    // Geometric blade angle at the reference section
+    fn blade_angle(&self) -> f32 {
+        let radius = 0.5 * self.diameter * REFERENCE_RADIUS_FRACTION;
+        (self.pitch / (2.0 * std::f32::consts::PI * radius)).atan()
+    }
+
+    // Prandtl tip-loss factor at the reference section for inflow angle φ
+    fn tip_loss_factor(&self, inflow_angle: f32) -> f32 {
+        let exponent = -0.5 * self.blade_count.max(1) as f32 * (1.0 - REFERENCE_RADIUS_FRACTION)
+            / (REFERENCE_RADIUS_FRACTION * inflow_angle.sin().max(1e-3));
+        (2.0 / std::f32::consts::PI * exponent.exp().min(1.0).acos()).clamp(0.05, 1.0)
+    }
+
+    // Thrust and power at a shaft speed. The blade element gives T = B·c·s·½ρW²(CL cos φ - CD sin φ)
+    // with CL from the angle between the pitch and the inflow, momentum theory gives T = 2ρAF(V + v)v,
+    // and the induced velocity v is found where the two agree. P = B·c·s·½ρW²(CL sin φ + CD cos φ)·Ωr.
+    pub fn at_rpm(&self, air_density: f32, airspeed: f32, rpm: f32) -> PropellerPoint {
+        let airspeed = airspeed.max(0.0);
+        let radius = 0.5 * self.diameter * REFERENCE_RADIUS_FRACTION;
+        let blade_speed = 2.0 * std::f32::consts::PI * rpm.max(0.0) / 60.0 * radius;
+        let idle = PropellerPoint { rpm, advance_ratio: self.advance_ratio(airspeed, rpm), ..Default::default() };
+        if blade_speed <= 0.0 {
+            return idle;
+        }
+
+        // Loading grows with Ω²r² along the blade; referred to the reference section it acts over R/(3·0.75²)
+        let loaded_span = 0.5 * self.diameter / (3.0 * REFERENCE_RADIUS_FRACTION.powi(2));
+        let blade_area = self.blade_count as f32 * self.blade_chord * loaded_span;
+        let stall_angle = SECTION_CL_MAX / SECTION_LIFT_SLOPE;
+
+        // Axial and tangential blade force, section angle of attack and inflow angle for an induced velocity
+        let element = |induced_velocity: f32| {
+            let inflow_angle = (airspeed + induced_velocity).atan2(blade_speed);
+            let angle_of_attack = self.blade_angle() - inflow_angle;
+            let (cl, cd) = if angle_of_attack > stall_angle {
+                (SECTION_CL_MAX, SECTION_CD_MIN + SECTION_DRAG_FACTOR * SECTION_CL_MAX.powi(2)
+                    + POST_STALL_DRAG_SLOPE * (angle_of_attack - stall_angle))
+            } else {
+                let cl = SECTION_LIFT_SLOPE * angle_of_attack;
+                (cl, SECTION_CD_MIN + SECTION_DRAG_FACTOR * cl.powi(2))
+            };
+            let force = 0.5 * air_density * (blade_speed.powi(2) + (airspeed + induced_velocity).powi(2)) * blade_area;
+            let (sin, cos) = inflow_angle.sin_cos();
+            (force * (cl * cos - cd * sin), force * (cl * sin + cd * cos), angle_of_attack, inflow_angle)
+        };
+        let momentum = |induced_velocity: f32, inflow_angle: f32| {
+            2.0 * air_density * self.disk_area() * self.tip_loss_factor(inflow_angle) * (airspeed + induced_velocity) * induced_velocity
+        };
+
+        // Blades at or below zero lift windmill; the pilot freewheels instead of driving them
+        let (static_thrust, _, angle_of_attack, _) = element(0.0);
+        if static_thrust <= 0.0 {
+            return PropellerPoint { blade_angle_of_attack: angle_of_attack, ..idle };
+        }
+
+        // Blade thrust falls and momentum thrust rises with the induced velocity
+        let residual = |induced_velocity: f32| {
+            let (thrust, _, _, inflow_angle) = element(induced_velocity);
+            thrust - momentum(induced_velocity, inflow_angle)
+        };
+        let mut low = 0.0;
+        let mut high = blade_speed.max(1.0);
+        for _ in 0..30 {
+            if residual(high) <= 0.0 {
+                break;
+            }
+            high *= 2.0;
+        }
+        for _ in 0..30 {
+            let mid = 0.5 * (low + high);
+            if residual(mid) > 0.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        let induced_velocity = 0.5 * (low + high);
+        let (thrust, tangential_force, angle_of_attack, _) = element(induced_velocity);
+        let thrust = thrust.max(0.0);
+        let shaft_power = (tangential_force * blade_speed).max(0.0);
+        PropellerPoint {
+            thrust,
+            shaft_power,
+            efficiency: if shaft_power > 0.0 { thrust * airspeed / shaft_power } else { 0.0 },
+            induced_velocity,
+            blade_angle_of_attack: angle_of_attack,
+            stalled: angle_of_attack > stall_angle,
+            ..idle
+        }
+    }
+
+    // Slowest shaft speed that gives the thrust; beyond full RPM the most the propeller gives, flagged as limited
+    pub fn power_for_thrust(&self, air_density: f32, thrust: f32, airspeed: f32) -> PropellerPoint {
+        if thrust <= 0.0 {
+            return self.at_rpm(air_density, airspeed, 0.0);
+        }
+        let full = self.at_rpm(air_density, airspeed, self.max_rpm);
+        if full.thrust < thrust {
+            return PropellerPoint { limited: true, ..full };
+        }
+
+        // Thrust rises with shaft speed
+        let mut low = 0.0;
+        let mut high = self.max_rpm;
+        for _ in 0..24 {
+            let mid = 0.5 * (low + high);
+            if self.at_rpm(air_density, airspeed, mid).thrust < thrust {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        self.at_rpm(air_density, airspeed, high)
+    }
+
+    // Fastest shaft speed the power turns the propeller at, up to full RPM; power it cannot absorb is reported unused
+    pub fn thrust_for_power(&self, air_density: f32, shaft_power: f32, airspeed: f32) -> PropellerPoint {
+        if shaft_power <= 0.0 {
+            return self.at_rpm(air_density, airspeed, 0.0);
+        }
+        let full = self.at_rpm(air_density, airspeed, self.max_rpm);
+        if full.shaft_power <= shaft_power {
+            return PropellerPoint { unused_power: shaft_power - full.shaft_power, ..full };
+        }
+
+        // Absorbed power rises with shaft speed
+        let mut low = 0.0;
+        let mut high = self.max_rpm;
+        for _ in 0..24 {
+            let mid = 0.5 * (low + high);
+            if self.at_rpm(air_density, airspeed, mid).shaft_power <= shaft_power {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        self.at_rpm(air_density, airspeed, low)
+    }
+}
@@ -0,0 +1,667 @@
+use serde::{Deserialize, Serialize};
+
+use crate::airfoil::SectionPolar;
+use crate::battery::Battery;
+use crate::flapping::FlappingCycle;
+use crate::lift_curve::LiftCurve;
+use crate::params::FlightParams;
+use crate::parasite::ParasiteDrag;
+use crate::pilot::PowerDurationCurve;
+use crate::performance::calculate_speed_sweep;
+use crate::physics::{
+    ground_roll_drag_coefficient, level_flight, FlightPhase,
+    APPROACH_SPEED_FACTOR, GROUND_LIFT_COEFFICIENT, ROTATION_SPEED_FACTOR, TOUCHDOWN_SPEED_FACTOR,
+};
+use crate::propeller::Propeller;
+use crate::structure::calculate_structural_properties;
+use crate::vortex_lattice::SpanLoading;
+
+const GRAVITY: f32 = 9.81;
+
+const FLARE_HEIGHT: f32 = 1.5;  // m
+
+// Guidance gains
+const SPEED_GAIN: f32 = 0.5;  // 1/s
+const PATH_GAIN: f32 = 1.5;  // 1/s
+const ALTITUDE_GAIN: f32 = 0.3;  // 1/s
+const MIN_FLARE_SINK_RATE: f32 = 0.15;  // m/s
+const MAX_FLIGHT_PATH_ANGLE: f32 = 0.35;  // rad
+const MAX_DESCENT_ANGLE: f32 = 0.1;  // rad, shallow approach so the flare can arrest the sink
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MissionProfile {
+    pub cruise_altitude: f32,  // m above the runway
+    pub cruise_distance: f32,  // m of level flight before descending
+    pub max_ground_roll: f32,  // m, takeoff is aborted beyond this
+    pub max_duration: f32,  // s
+    pub time_step: f32,  // s
+}
+
+impl Default for MissionProfile {
+    fn default() -> Self {
+        Self {
+            cruise_altitude: 10.0,  // Human-powered aircraft fly low, in ground-effect range
+            cruise_distance: 2000.0,
+            max_ground_roll: 1000.0,
+            max_duration: 3600.0,
+            time_step: 0.02,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum MissionPhase {
+    GroundRoll,
+    Climb,
+    Cruise,
+    Descent,
+    Flare,
+    Rollout,
+    Complete,
+    Aborted,
+}
+
+impl MissionPhase {
+    pub fn flight_phase(&self, airspeed: f32) -> FlightPhase {
+        match self {
+            MissionPhase::GroundRoll if airspeed > 0.1 => FlightPhase::Takeoff,
+            MissionPhase::GroundRoll => FlightPhase::OnGround,
+            MissionPhase::Climb | MissionPhase::Cruise | MissionPhase::Descent => FlightPhase::InFlight,
+            MissionPhase::Flare | MissionPhase::Rollout => FlightPhase::Landing,
+            MissionPhase::Complete | MissionPhase::Aborted => FlightPhase::OnGround,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self, MissionPhase::Complete | MissionPhase::Aborted)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct MissionState {
+    pub time: f32,  // s
+    pub phase: MissionPhase,
+
+    pub distance: f32,  // m over the ground
+    pub altitude: f32,  // m above the runway
+    pub airspeed: f32,  // m/s
+    pub flight_path_angle: f32,  // rad, positive climbing
+
+    pub battery_state_of_charge: f32,  // 0-1
+    pub battery_energy: f32,  // Wh remaining at nominal voltage
+    pub battery_voltage: f32,  // V at the terminals under the current load
+    pub pilot_fatigue: f32,  // 0 = fresh, 1 = anaerobic capacity (W') exhausted
+
+    pub angle_of_attack: f32,  // deg, wing
+    pub lift_coefficient: f32,
+    pub lift: f32,
+    pub drag: f32,
+    pub thrust: f32,
+    pub pilot_power: f32,
+    pub motor_power: f32,  // Electrical draw, W
+    pub flapping: bool,  // Wings beating this step
+}
+
+impl MissionState {
+    pub fn flight_phase(&self) -> FlightPhase {
+        self.phase.flight_phase(self.airspeed)
+    }
+
+    pub fn climb_rate(&self) -> f32 {
+        self.airspeed * self.flight_path_angle.sin()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MissionResult {
+    pub completed: bool,
+    pub abort_reason: Option<String>,
+
+    pub liftoff_time: Option<f32>,
+    pub ground_roll_distance: Option<f32>,
+    pub max_altitude: f32,
+    pub total_distance: f32,
+    pub flight_time: f32,  // s airborne
+    pub mission_time: f32,
+    pub battery_used: f32,  // Wh
+    pub peak_pilot_fatigue: f32,
+    pub touchdown_airspeed: Option<f32>,
+    pub touchdown_sink_rate: Option<f32>,
+    pub rollout_distance: Option<f32>,
+
+    pub samples: Vec<MissionState>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Touchdown {
+    time: f32,
+    distance: f32,
+    airspeed: f32,
+    sink_rate: f32,
+}
+
+pub struct MissionSimulator {
+    params: FlightParams,
+    profile: MissionProfile,
+    propeller: Propeller,
+    battery: Battery,
+    section: SectionPolar,
+    lift_curve: LiftCurve,
+    span_loading: SpanLoading,
+    parasite_drag: ParasiteDrag,  // At the design airspeed
+
+    mass: f32,
+    weight: f32,
+    wing_area: f32,
+    air_density: f32,
+    stall_speed: f32,
+    climb_speed: f32,
+    cruise_speed: f32,
+    approach_speed: f32,
+    flapping: FlappingCycle,  // Stroke-averaged at the cruise speed, flapping from rotation onwards
+    pilot: PowerDurationCurve,
+    anaerobic_balance: f32,  // J of W' left
+    battery_capacity: f32,  // Wh
+    battery_energy_drawn: f32,  // Wh delivered plus internal losses
+
+    state: MissionState,
+    abort_reason: Option<String>,
+    liftoff: Option<(f32, f32)>,  // time, distance
+    touchdown: Option<Touchdown>,
+    forced_landing: bool,
+    max_altitude: f32,
+    peak_fatigue: f32,
+}
+
+impl MissionSimulator {
+    pub fn new(params: &FlightParams, profile: &MissionProfile) -> Self {
+        let structural = calculate_structural_properties(params);
+        let atmosphere = params.atmosphere();
+        let mass = params.pilot_mass + structural.total_structural_mass;
+        let weight = mass * GRAVITY;
+        let wing_area = params.wing_area();
+        let section = SectionPolar::from_params(params);
+        let stall_speed = (2.0 * weight / (atmosphere.density * wing_area * section.cl_max)).sqrt();
+        let ground_lift_coefficient = GROUND_LIFT_COEFFICIENT.min(section.cl_max);
+        let lift_curve = LiftCurve::from_section(params, &section);
+        let ground_angle_of_attack = lift_curve.angle_for_lift(ground_lift_coefficient).unwrap_or(lift_curve.stall_angle);
+
+        // Guidance speeds come from the level-flight polar
+        let sweep = calculate_speed_sweep(params);
+        let min_speed = stall_speed * APPROACH_SPEED_FACTOR;
+        let climb_speed = sweep.v_min_power.unwrap_or(min_speed).max(min_speed);
+        let cruise_speed = (params.forward_speed - params.wind_speed).max(min_speed);
+
+        let pilot = PowerDurationCurve::from_params(params);
+        let battery = Battery::from_params(params);
+        let battery_capacity = battery.capacity;
+        let initial_charge = if battery_capacity > 0.0 { 1.0 } else { 0.0 };
+        let initial_voltage = battery.open_circuit_voltage(initial_charge);
+
+        Self {
+            params: params.clone(),
+            profile: profile.clone(),
+            propeller: Propeller::from_params(params),
+            battery,
+            section,
+            lift_curve,
+            span_loading: SpanLoading::from_params(params),
+            parasite_drag: ParasiteDrag::from_params(params),
+            mass,
+            weight,
+            wing_area,
+            air_density: atmosphere.density,
+            stall_speed,
+            climb_speed,
+            cruise_speed,
+            approach_speed: min_speed,
+            flapping: level_flight(params, atmosphere.density, weight, cruise_speed).1,
+            pilot: pilot.clone(),
+            anaerobic_balance: pilot.anaerobic_capacity,
+            battery_capacity,
+            battery_energy_drawn: 0.0,
+            state: MissionState {
+                time: 0.0,
+                phase: MissionPhase::GroundRoll,
+                distance: 0.0,
+                altitude: 0.0,
+                airspeed: params.headwind(),  // Standing still in the wind
+                flight_path_angle: 0.0,
+                battery_state_of_charge: initial_charge,
+                battery_energy: battery_capacity,
+                battery_voltage: initial_voltage,
+                pilot_fatigue: 0.0,
+                angle_of_attack: ground_angle_of_attack,
+                lift_coefficient: ground_lift_coefficient,
+                lift: 0.0,
+                drag: 0.0,
+                thrust: 0.0,
+                pilot_power: 0.0,
+                motor_power: 0.0,
+                flapping: false,
+            },
+            abort_reason: None,
+            liftoff: None,
+            touchdown: None,
+            forced_landing: false,
+            max_altitude: 0.0,
+            peak_fatigue: 0.0,
+        }
+    }
+
+    pub fn state(&self) -> &MissionState {
+        &self.state
+    }
+
+    pub fn params(&self) -> &FlightParams {
+        &self.params
+    }
+
+    pub fn profile(&self) -> &MissionProfile {
+        &self.profile
+    }
+
+    pub fn stall_speed(&self) -> f32 {
+        self.stall_speed
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    pub fn battery_fraction(&self) -> f32 {
+        if self.battery_capacity > 0.0 {
+            let minimum = self.battery.minimum_state_of_charge();
+            ((self.state.battery_state_of_charge - minimum) / (1.0 - minimum).max(1e-6)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state.phase.is_finished()
+    }
+
+    // Advance by `duration` seconds using the profile's fixed time step
+    pub fn advance(&mut self, duration: f32) {
+        let dt = self.profile.time_step.max(1e-3);
+        let mut remaining = duration;
+        while remaining > 0.0 && !self.is_finished() {
+            self.step(dt.min(remaining));
+            remaining -= dt;
+        }
+    }
+
+    pub fn run(mut self) -> MissionResult {
+        let sample_interval = 0.5;
+        let mut samples = vec![self.state];
+        let mut next_sample = sample_interval;
+
+        while !self.is_finished() {
+            self.step(self.profile.time_step.max(1e-3));
+            if self.state.time >= next_sample || self.is_finished() {
+                samples.push(self.state);
+                next_sample += sample_interval;
+            }
+        }
+
+        self.result(samples)
+    }
+
+    pub fn result(&self, samples: Vec<MissionState>) -> MissionResult {
+        let flight_time = self.liftoff.map_or(0.0, |(liftoff_time, _)| {
+            let end = self.touchdown.map_or(self.state.time, |touchdown| touchdown.time);
+            (end - liftoff_time).max(0.0)
+        });
+
+        MissionResult {
+            completed: self.state.phase == MissionPhase::Complete && !self.forced_landing,
+            abort_reason: self.abort_reason.clone(),
+            liftoff_time: self.liftoff.map(|(time, _)| time),
+            ground_roll_distance: self.liftoff.map(|(_, distance)| distance),
+            max_altitude: self.max_altitude,
+            total_distance: self.state.distance,
+            flight_time,
+            mission_time: self.state.time,
+            battery_used: self.battery_energy_drawn,
+            peak_pilot_fatigue: self.peak_fatigue,
+            touchdown_airspeed: self.touchdown.map(|touchdown| touchdown.airspeed),
+            touchdown_sink_rate: self.touchdown.map(|touchdown| touchdown.sink_rate),
+            rollout_distance: self.touchdown.map(|touchdown| self.state.distance - touchdown.distance),
+            samples,
+        }
+    }
+
+    fn abort(&mut self, reason: &str) {
+        self.state.phase = MissionPhase::Aborted;
+        self.abort_reason = Some(reason.to_string());
+    }
+
+    // Stroke-averaged lift over the fixed-wing lift at the same angle, found at the cruise speed
+    fn lift_factor(&self, flapping: bool) -> f32 {
+        if flapping { self.flapping.lift_factor } else { 1.0 }
+    }
+
+    // Maximum power the pilot can deliver at the pedals right now
+    fn pilot_power_limit(&self) -> f32 {
+        let critical_power = self.pilot.critical_power_at(self.state.time);
+        if self.anaerobic_balance > 0.0 {
+            self.pilot.peak_power.max(critical_power)
+        } else {
+            critical_power
+        }
+    }
+
+    // Motor output is capped by what the pack can still deliver through its internal resistance
+    fn motor_power_limit(&self) -> f32 {
+        if self.battery_capacity > 0.0 && self.state.battery_state_of_charge > self.battery.minimum_state_of_charge() {
+            let pack_limit = self.battery.max_power(self.state.battery_state_of_charge) * self.params.motor_efficiency;
+            self.params.motor_shaft_power().min(pack_limit)
+        } else {
+            0.0
+        }
+    }
+
+    // Shaft power available from both sources after the drivetrain
+    fn shaft_power_limit(&self) -> f32 {
+        self.params.pilot_shaft_power(self.pilot_power_limit()) + self.motor_power_limit()
+    }
+
+    // Most shaft power the propeller absorbs, at full RPM
+    fn propeller_power_limit(&self, airspeed: f32) -> f32 {
+        self.propeller.at_rpm(self.air_density, airspeed, self.propeller.max_rpm).shaft_power
+    }
+
+    fn thrust_from_power(&self, power: f32, airspeed: f32) -> f32 {
+        self.propeller.thrust_for_power(self.air_density, power, airspeed).thrust
+    }
+
+    // The pilot beats the wings only when the shaft power pays for the whole stroke; a part stroke
+    // gives far less thrust per watt than the propeller, so short of that the wings are held still
+    fn can_flap(&self, shaft_power: f32) -> bool {
+        shaft_power >= self.flapping.total_power()
+    }
+
+    // Wings are paid first when they beat, the propeller takes what is left
+    fn thrust_with_flapping(&self, shaft_power: f32, flapping: bool, airspeed: f32) -> f32 {
+        if flapping {
+            self.thrust_from_power(shaft_power - self.flapping.total_power(), airspeed) + self.flapping.thrust
+        } else {
+            self.thrust_from_power(shaft_power, airspeed)
+        }
+    }
+
+    // Flight-path angle that drives airspeed towards the target (pitch-for-speed)
+    fn speed_hold_gamma(&self, thrust: f32, drag: f32, airspeed_target: f32) -> f32 {
+        let desired_acceleration = SPEED_GAIN * (airspeed_target - self.state.airspeed);
+        let sin_gamma = (thrust - drag - self.mass * desired_acceleration) / self.weight;
+        sin_gamma.clamp(-MAX_FLIGHT_PATH_ANGLE.sin(), MAX_FLIGHT_PATH_ANGLE.sin()).asin()
+    }
+
+    pub fn step(&mut self, dt: f32) {
+        if self.is_finished() {
+            return;
+        }
+        if self.state.time >= self.profile.max_duration {
+            self.abort("mission exceeded the maximum duration");
+            return;
+        }
+
+        match self.state.phase {
+            MissionPhase::GroundRoll | MissionPhase::Rollout => self.step_ground(dt),
+            _ => self.step_airborne(dt),
+        }
+
+        self.state.time += dt;
+        self.max_altitude = self.max_altitude.max(self.state.altitude);
+        self.peak_fatigue = self.peak_fatigue.max(self.state.pilot_fatigue);
+    }
+
+    fn step_ground(&mut self, dt: f32) {
+        let headwind = self.params.headwind();
+        let slope = self.params.runway_slope.atan();
+        let airspeed = self.state.airspeed;
+        let ground_speed = (airspeed - headwind).max(0.0);
+        let q = 0.5 * self.air_density * airspeed.powi(2);
+        let cl = GROUND_LIFT_COEFFICIENT.min(self.section.cl_max);
+        let lift = cl * q * self.wing_area;
+        let drag = ground_roll_drag_coefficient(&self.params) * q * self.wing_area * airspeed.signum();
+        let normal_force = (self.weight * slope.cos() - lift).max(0.0);
+
+        let (thrust, friction) = if self.state.phase == MissionPhase::GroundRoll {
+            // The wings are held still on the ground and only start beating at rotation
+            let shaft_power = self.split_power(self.propeller_power_limit(airspeed.max(0.0)), dt);
+            (self.thrust_from_power(shaft_power, airspeed.max(0.0)), self.params.rolling_friction() * normal_force)
+        } else {
+            self.state.pilot_power = 0.0;
+            self.state.motor_power = 0.0;
+            self.recover_pilot(0.0, dt);
+            (0.0, self.params.braking_friction * normal_force)
+        };
+
+        let acceleration = (thrust - drag - friction - self.weight * slope.sin()) / self.mass;
+        let ground_speed = (ground_speed + acceleration * dt).max(0.0);
+        self.state.airspeed = ground_speed + headwind;
+        self.state.distance += ground_speed * dt;
+        self.state.flight_path_angle = 0.0;
+        self.state.altitude = 0.0;
+        self.state.angle_of_attack = self.lift_curve.angle_for_lift(cl).unwrap_or(self.lift_curve.stall_angle);
+        self.state.lift_coefficient = cl;
+        self.state.lift = lift;
+        self.state.drag = drag;
+        self.state.thrust = thrust;
+
+        if self.state.phase == MissionPhase::GroundRoll {
+            let rotation_speed = self.stall_speed * ROTATION_SPEED_FACTOR;
+            if self.state.airspeed >= rotation_speed {
+                self.liftoff = Some((self.state.time, self.state.distance));
+                self.state.phase = MissionPhase::Climb;
+            } else if self.state.distance > self.profile.max_ground_roll {
+                self.abort("could not reach rotation speed within the available ground roll");
+            } else if self.state.time > 5.0 && acceleration <= 0.0 {
+                self.abort("insufficient thrust to accelerate to rotation speed");
+            }
+        } else if ground_speed < 0.5 {
+            self.state.airspeed = headwind;
+            self.state.phase = MissionPhase::Complete;
+        }
+    }
+
+    // Pilot supplies shaft power first through the drivetrain, the motor covers the remainder.
+    // Returns the total shaft power delivered.
+    fn split_power(&mut self, shaft_demand: f32, dt: f32) -> f32 {
+        let demand = shaft_demand.max(0.0);
+        let pilot_shaft = demand.min(self.params.pilot_shaft_power(self.pilot_power_limit()));
+        let pilot_power = if self.params.drivetrain_efficiency > 0.0 {
+            pilot_shaft / self.params.drivetrain_efficiency
+        } else {
+            0.0
+        };
+        let motor_shaft = (demand - pilot_shaft).min(self.motor_power_limit());
+
+        self.recover_pilot(pilot_power, dt);
+
+        let motor_draw = if self.params.motor_efficiency > 0.0 {
+            motor_shaft / self.params.motor_efficiency
+        } else {
+            0.0
+        };
+        if self.battery_capacity > 0.0 {
+            let (state_of_charge, load) = self.battery.discharge(self.state.battery_state_of_charge, motor_draw, dt);
+            self.battery_energy_drawn += (load.power + load.resistive_loss) * dt / 3600.0;
+            self.state.battery_state_of_charge = state_of_charge;
+            self.state.battery_energy = state_of_charge * self.battery_capacity;
+            self.state.battery_voltage = load.terminal_voltage;
+        }
+        self.state.pilot_power = pilot_power;
+        self.state.motor_power = motor_draw;
+        pilot_shaft + motor_shaft
+    }
+
+    // W' drains above critical power and recovers below it
+    fn recover_pilot(&mut self, pilot_power: f32, dt: f32) {
+        self.anaerobic_balance = self.pilot.update_balance(self.anaerobic_balance, pilot_power, self.state.time, dt);
+        self.state.pilot_fatigue = if self.pilot.anaerobic_capacity > 0.0 {
+            1.0 - self.anaerobic_balance / self.pilot.anaerobic_capacity
+        } else if pilot_power > self.pilot.critical_power_at(self.state.time) {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    // Propeller shaft power to make up a thrust shortfall, none when there is none
+    fn propeller_power(&self, required_thrust: f32, airspeed: f32) -> f32 {
+        if required_thrust <= 0.0 {
+            return 0.0;
+        }
+        self.propeller.power_for_thrust(self.air_density, required_thrust, airspeed).shaft_power
+    }
+
+    fn step_airborne(&mut self, dt: f32) {
+        let airspeed = self.state.airspeed.max(0.1);
+        let gamma = self.state.flight_path_angle;
+        let q = 0.5 * self.air_density * airspeed.powi(2);
+        let previous_drag = self.state.drag;
+
+        // Power and target flight path per phase
+        let (power_demand, gamma_target) = match self.state.phase {
+            MissionPhase::Climb => {
+                let power = self.shaft_power_limit();
+                let flapping = self.can_flap(power);
+                let thrust = self.thrust_with_flapping(power, flapping, airspeed);
+                let flapping_power = if flapping { self.flapping.total_power() } else { 0.0 };
+                (flapping_power + self.propeller_power_limit(airspeed), self.speed_hold_gamma(thrust, previous_drag, self.climb_speed).max(0.0))
+            }
+            MissionPhase::Cruise => {
+                let altitude_error = self.profile.cruise_altitude - self.state.altitude;
+                let hold_gamma = (ALTITUDE_GAIN * altitude_error / airspeed).clamp(-0.1, 0.1);
+                let required_thrust = previous_drag
+                    + self.weight * hold_gamma.sin()
+                    + self.mass * SPEED_GAIN * (self.cruise_speed - airspeed);
+                let power = self.shaft_power_limit();
+                let flapping = self.can_flap(power);
+                let demand = if flapping {
+                    self.propeller_power(required_thrust - self.flapping.thrust, airspeed) + self.flapping.total_power()
+                } else {
+                    self.propeller_power(required_thrust, airspeed)
+                };
+
+                // Below the approach speed the pilot trades height for speed instead
+                let available_thrust = self.thrust_with_flapping(power, flapping, airspeed);
+                let speed_gamma = self.speed_hold_gamma(available_thrust, previous_drag, self.cruise_speed);
+                let gamma = if airspeed < self.stall_speed * 1.1 { hold_gamma.min(speed_gamma) } else { hold_gamma };
+                (demand, gamma)
+            }
+            MissionPhase::Descent => {
+                // Power off unless the glide is steeper than the approach allows
+                let gamma = self.speed_hold_gamma(0.0, previous_drag, self.approach_speed).clamp(-MAX_DESCENT_ANGLE, 0.0);
+                let required_thrust = previous_drag
+                    + self.weight * gamma.sin()
+                    + self.mass * SPEED_GAIN * (self.approach_speed - airspeed);
+                (self.propeller_power(required_thrust, airspeed), gamma)
+            }
+            MissionPhase::Flare => {
+                // Hold off just enough that drag bleeds the approach speed down to touchdown speed as the
+                // wheels reach the runway, never sinking faster than the glide or the approach angle
+                let touchdown_speed = self.stall_speed * TOUCHDOWN_SPEED_FACTOR;
+                // Sinking at w from height h takes h/w, over which drag less the gravity component along the
+                // path, D − W·w/V, removes V − V_td of speed; solved for w
+                let altitude = self.state.altitude;
+                let excess_speed = (airspeed - touchdown_speed).max(0.0) + GRAVITY * altitude / airspeed;
+                let max_sink_rate = (airspeed * previous_drag / self.weight)
+                    .min(airspeed * MAX_DESCENT_ANGLE.sin())
+                    .max(MIN_FLARE_SINK_RATE);
+                let sink_rate = (altitude * previous_drag / self.mass / excess_speed.max(1e-3)).clamp(MIN_FLARE_SINK_RATE, max_sink_rate);
+                let gamma = -(sink_rate / airspeed).min(1.0).asin();
+
+                // Power makes up the difference when drag alone would bleed speed too fast on that path
+                let deceleration = (airspeed - touchdown_speed).max(0.0) * sink_rate / altitude.max(1e-3);
+                let required_thrust = previous_drag + self.weight * gamma.sin() - self.mass * deceleration;
+                (self.propeller_power(required_thrust, airspeed), gamma)
+            }
+            _ => (0.0, 0.0),
+        };
+
+        // The wings flap while the pilot pedals to climb or cruise and are held still on the approach
+        let shaft_power = self.split_power(power_demand, dt);
+        let flapping = power_demand > 0.0
+            && matches!(self.state.phase, MissionPhase::Climb | MissionPhase::Cruise)
+            && self.can_flap(shaft_power);
+        let thrust = self.thrust_with_flapping(shaft_power, flapping, airspeed);
+
+        // Lift to follow the commanded flight path; the pilot pitches no further than the stall angle
+        let lift_command = self.weight * gamma.cos() + self.mass * airspeed * PATH_GAIN * (gamma_target - gamma);
+        let lift_factor = self.lift_factor(flapping);
+        let cl = (lift_command / (q * self.wing_area)).clamp(0.0, self.section.cl_max * lift_factor);
+        let wing_cl = cl / lift_factor;
+        let angle_of_attack = self.lift_curve.angle_for_lift(wing_cl).unwrap_or(self.lift_curve.stall_angle);
+        let lift = cl * q * self.wing_area;
+        let drag_coefficient = self.section.profile_drag(wing_cl) + self.span_loading.induced_drag_coefficient(cl) + self.parasite_drag.drag_coefficient;
+        let drag = drag_coefficient * q * self.wing_area;
+
+        // Point-mass longitudinal equations of motion
+        let acceleration = (thrust - drag - self.weight * gamma.sin()) / self.mass;
+        let gamma_rate = (lift - self.weight * gamma.cos()) / (self.mass * airspeed);
+
+        self.state.airspeed = (airspeed + acceleration * dt).max(0.1);
+        self.state.flight_path_angle = (gamma + gamma_rate * dt).clamp(-1.2, 1.2);
+        self.state.altitude += self.state.airspeed * self.state.flight_path_angle.sin() * dt;
+        self.state.distance += (self.state.airspeed * self.state.flight_path_angle.cos() + self.params.wind_speed) * dt;
+        self.state.angle_of_attack = angle_of_attack;
+        self.state.lift_coefficient = cl;
+        self.state.lift = lift;
+        self.state.drag = drag;
+        self.state.thrust = thrust;
+        self.state.flapping = flapping;
+
+        if self.state.altitude <= 0.0 {
+            let sink_rate = -self.state.climb_rate();
+            if self.state.phase == MissionPhase::Climb && self.max_altitude < FLARE_HEIGHT {
+                // Still skimming the runway after rotation, building speed before climbing away
+                self.state.altitude = 0.0;
+                self.state.flight_path_angle = self.state.flight_path_angle.max(0.0);
+                let skim_distance = self.state.distance - self.liftoff.map_or(0.0, |(_, distance)| distance);
+                if skim_distance > self.profile.max_ground_roll {
+                    self.abort("could not climb away after rotation");
+                }
+                return;
+            }
+            if matches!(self.state.phase, MissionPhase::Climb | MissionPhase::Cruise) {
+                self.forced_landing = true;
+                self.abort_reason = Some("forced landing: not enough power to hold the cruise".to_string());
+            }
+            self.state.altitude = 0.0;
+            self.state.flight_path_angle = 0.0;
+            self.touchdown = Some(Touchdown {
+                time: self.state.time,
+                distance: self.state.distance,
+                airspeed: self.state.airspeed,
+                sink_rate,
+            });
+            self.state.phase = MissionPhase::Rollout;
+            return;
+        }
+
+        match self.state.phase {
+            MissionPhase::Climb if self.state.altitude >= self.profile.cruise_altitude * 0.98 => {
+                self.state.phase = MissionPhase::Cruise;
+            }
+            MissionPhase::Cruise => {
+                let cruise_end = self.liftoff.map_or(0.0, |(_, distance)| distance) + self.profile.cruise_distance;
+                if self.state.distance >= cruise_end {
+                    self.state.phase = MissionPhase::Descent;
+                }
+            }
+            MissionPhase::Descent if self.state.altitude <= FLARE_HEIGHT => {
+                self.state.phase = MissionPhase::Flare;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn simulate_mission(params: &FlightParams, profile: &MissionProfile) -> MissionResult {
+    MissionSimulator::new(params, profile).run()
+}
@@ -0,0 +1,169 @@
+use serde::Serialize;
+
+use crate::airfoil::SectionPolar;
+use crate::battery::Battery;
+use crate::laminate::Laminate;
+use crate::params::{FlightParams, SparSection};
+
+const SPAR_WIDTH: f32 = 0.02;  // m, rectangular spar
+
+// Root cross-section of the spar
+#[derive(Clone, Debug, Serialize)]
+pub struct SparProperties {
+    pub section: SparSection,
+    pub depth: f32,  // m, height of the rectangle or outer diameter of the tube
+    pub wall_thickness: f32,  // m, tube only
+    pub area: f32,  // m² of material in the section
+    pub second_moment: f32,  // m⁴ about the bending axis
+    pub modulus: f32,  // Pa along the spar
+    pub density: f32,  // kg/m³
+    pub bending_strength: f32,  // Pa, the weaker of tension and compression
+    pub shear_strength: f32,  // Pa
+    pub fatigue_limit: f32,  // Pa
+    pub cost_per_kg: f32,  // USD
+    pub laminate: Option<Laminate>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StructuralAnalysis {
+    pub wing_mass: f32,
+    pub spar: SparProperties,
+    pub spar_mass: f32,
+    pub battery_mass: f32,
+    pub total_structural_mass: f32,
+    pub max_load_factor: f32,
+    pub wing_deflection: f32,
+    pub critical_flutter_speed: f32,
+    pub root_bending_stress: f32,  // Pa at the design load factor
+    pub root_shear_stress: f32,  // Pa at the design load factor
+    pub cruise_bending_stress: f32,  // Pa in 1 g flight
+    pub strength_margin: f32,  // Allowable over applied stress at the design load, bending or shear
+    pub fatigue_margin: f32,  // Fatigue limit over the 1 g bending stress
+    pub material_cost: f32,  // USD of skin and spar material for all wings
+    pub structural_feasible: bool,
+}
+
+pub fn calculate_structural_properties(params: &FlightParams) -> StructuralAnalysis {
+    let planform = params.planform();
+    let wing_area_single = planform.area();
+    let spar_length = planform.spar_length();
+    
+    let wing_density = params.wing_material.density;
+    let wing_youngs_modulus = params.wing_material.modulus * 1e9;  // Pa
+    
+    // A laminate replaces the isotropic spar material
+    let laminate = Laminate::from_plies(&params.spar_layup);
+    let spar_material = &params.spar_material;
+    let (spar_density, spar_youngs_modulus, spar_bending_strength, spar_shear_strength, spar_fatigue_limit, spar_cost) = match &laminate {
+        Some(laminate) => (
+            laminate.density,
+            laminate.axial_modulus,
+            laminate.tensile_strength.min(laminate.compressive_strength),
+            laminate.shear_strength,
+            laminate.fatigue_limit,
+            laminate.cost_per_kg,
+        ),
+        None => (
+            spar_material.density,
+            spar_material.modulus * 1e9,
+            spar_material.bending_strength() * 1e6,
+            spar_material.shear_strength * 1e6,
+            spar_material.fatigue_limit * 1e6,
+            spar_material.cost_per_kg,
+        ),
+    };
+    
+    // Skin and spar depth follow the local chord; the spar is sized by its root depth
+    let effective_thickness = planform.mean_aerodynamic_chord() * params.wing_thickness_ratio;
+    let wing_skin_mass = wing_area_single * wing_density * effective_thickness * 0.01;  // kg, corrected thickness
+    
+    let spar_height = planform.root_chord() * params.wing_thickness_ratio * 0.8;
+    let (wall_thickness, spar_area, moment_of_inertia, spar_mass) = match params.spar_section {
+        SparSection::Rectangular => {
+            // The beam tapers with the wing, so its volume follows the mean depth
+            let spar_volume = wing_area_single * params.wing_thickness_ratio * 0.8 * SPAR_WIDTH * spar_length / planform.span().max(1e-6);  // m³
+            (0.0, spar_height * SPAR_WIDTH, spar_height.powi(3) * SPAR_WIDTH / 12.0, spar_volume * spar_density)
+        }
+        SparSection::Tube => {
+            // Constant diameter from root to tip, as drawn tubes and mandrel-wound spars are
+            let wall = laminate.as_ref().map_or(params.spar_wall_thickness / 1000.0, |laminate| laminate.thickness).min(0.5 * spar_height);
+            let bore = spar_height - 2.0 * wall;
+            let area = std::f32::consts::PI / 4.0 * (spar_height.powi(2) - bore.powi(2));
+            let second_moment = std::f32::consts::PI / 64.0 * (spar_height.powi(4) - bore.powi(4));
+            (wall, area, second_moment, area * spar_length * spar_density)
+        }
+    };
+    let wing_mass = wing_skin_mass + spar_mass + 1.5;  // +1.5kg for ribs, hardware
+    
+    let battery_mass = Battery::from_params(params).mass();
+    let total_structural_mass = wing_mass * params.equivalent_wing_count() + params.motor_mass + battery_mass;
+    
+    let dynamic_pressure = 0.5 * params.atmosphere().density * params.forward_speed.powi(2);
+    let max_lift_per_wing = SectionPolar::from_params(params).cl_max * dynamic_pressure * wing_area_single;
+    let total_weight = (params.pilot_mass + total_structural_mass) * 9.81;
+    let max_load_factor = (max_lift_per_wing * params.equivalent_wing_count()) / total_weight;
+    
+    // Lift acts at the area centroid, so taper moves it inboard and relieves the root
+    let lift_arm = planform.centroid_span() * spar_length / planform.span().max(1e-6);
+    let root_bending_moment = max_lift_per_wing * lift_arm;
+    
+    let effective_modulus = wing_youngs_modulus * 0.1 + spar_youngs_modulus * 0.9;  // Spar carries most load
+    // Cantilever tip deflection M·L²/(4EI), equal to qL⁴/(8EI) for a uniform load
+    let wing_deflection = (root_bending_moment * spar_length.powi(2)) / (4.0 * effective_modulus * moment_of_inertia);
+    
+    // Credible flutter speed based on wing stiffness and mass distribution
+    let flutter_parameter = (effective_modulus * moment_of_inertia) / (spar_density * wing_area_single * spar_length.powi(4));
+    let critical_flutter_speed = flutter_parameter.sqrt() * 20.0;  // Empirical scaling
+    
+    // The spar alone carries the root bending and shear of the design load, shared by the wings
+    // in proportion to their area; the 1 g stress cycles with every gust and wing beat
+    let lift_per_wing = total_weight / params.equivalent_wing_count().max(1e-6);  // N in 1 g flight
+    let cruise_bending_stress = lift_per_wing * lift_arm * 0.5 * spar_height / moment_of_inertia.max(1e-12);
+    let root_bending_stress = params.wing_safety_factor * cruise_bending_stress;
+    let shear_shape_factor = match params.spar_section {
+        SparSection::Rectangular => 1.5,  // Peak over mean shear of a rectangle
+        SparSection::Tube => 2.0,  // Thin-walled round tube
+    };
+    let root_shear_stress = shear_shape_factor * params.wing_safety_factor * lift_per_wing / spar_area.max(1e-9);
+    let strength_margin = (spar_bending_strength / root_bending_stress.max(1e-6)).min(spar_shear_strength / root_shear_stress.max(1e-6));
+    let fatigue_margin = spar_fatigue_limit / cruise_bending_stress.max(1e-6);
+    
+    let material_cost = (wing_skin_mass * params.wing_material.cost_per_kg + spar_mass * spar_cost) * params.equivalent_wing_count();
+    
+    let structural_feasible = max_load_factor >= params.wing_safety_factor && 
+                            wing_deflection < spar_length * 0.1 &&
+                            critical_flutter_speed > params.forward_speed * 1.5 &&
+                            strength_margin >= 1.0 &&
+                            fatigue_margin >= 1.0;
+    
+    StructuralAnalysis {
+        wing_mass,
+        spar: SparProperties {
+            section: params.spar_section,
+            depth: spar_height,
+            wall_thickness,
+            area: spar_area,
+            second_moment: moment_of_inertia,
+            modulus: spar_youngs_modulus,
+            density: spar_density,
+            bending_strength: spar_bending_strength,
+            shear_strength: spar_shear_strength,
+            fatigue_limit: spar_fatigue_limit,
+            cost_per_kg: spar_cost,
+            laminate,
+        },
+        spar_mass,
+        battery_mass,
+        total_structural_mass,
+        max_load_factor,
+        wing_deflection,
+        critical_flutter_speed,
+        root_bending_stress,
+        root_shear_stress,
+        cruise_bending_stress,
+        strength_margin,
+        fatigue_margin,
+        material_cost,
+        structural_feasible,
+    }
+}
@@ -0,0 +1,353 @@
+use std::cell::RefCell;
+use std::f32::consts::PI;
+
+use serde::Serialize;
+
+use crate::multiplane::surface_placements;
+use crate::numeric::solve_linear;
+use crate::params::FlightParams;
+use crate::planform::WingPlanform;
+
+const STRIPS_PER_PANEL: usize = 12;  // Per wing panel, cosine spaced over the span, finest at the tips
+const CORE_RADIUS: f32 = 1e-4;  // m, below this a vortex line induces nothing
+
+// One lifting surface: a left and a right wing meeting on the centreline
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LiftingSurface {
+    pub wing: WingPlanform,  // Right wing, mirrored to the left
+    pub x_offset: f32,  // m of quarter-chord stagger behind the first surface
+    pub z_offset: f32,  // m of gap above the first surface
+    pub incidence: f32,  // deg of decalage relative to the main wing root
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Planform {
+    pub surfaces: Vec<LiftingSurface>,
+}
+
+// Local section lift coefficient at one strip: cl = basic + additional × CL
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct SpanStation {
+    pub surface: usize,
+    pub y: f32,  // m from the centreline
+    pub chord: f32,  // m
+    pub width: f32,  // m, projected
+    pub additional: f32,  // Per unit of aircraft CL
+    pub basic: f32,  // From twist alone, at zero aircraft CL
+}
+
+// Vortex-lattice solution for a planform: one horseshoe vortex per spanwise strip with its
+// bound leg on the quarter chord, flow tangency at the three-quarter chord and the induced
+// drag taken in the Trefftz plane. Sections are thin (2π) here; lift_curve corrects the slope.
+#[derive(Clone, Debug, Serialize)]
+pub struct SpanLoading {
+    pub planform: Planform,
+    pub reference_area: f32,  // m², all panels
+    pub reference_span: f32,  // m, widest surface
+    pub aspect_ratio: f32,  // Reference span² over reference area
+    pub lift_slope: f32,  // per rad
+    pub zero_alpha_lift: f32,  // CL at zero angle of attack from twist and decalage
+    pub stations: Vec<SpanStation>,
+    induced_drag_terms: [f32; 3],  // CDi = k0 + k1·s + k2·s² with s = sin α
+}
+
+impl LiftingSurface {
+    pub fn area(&self) -> f32 {
+        2.0 * self.wing.area()
+    }
+
+    // Tip to tip, projected onto the horizontal
+    pub fn span(&self) -> f32 {
+        2.0 * self.wing.projected_span()
+    }
+}
+
+impl Planform {
+    // Wings are paired into surfaces and arranged by the layout
+    pub fn from_params(params: &FlightParams) -> Self {
+        let wing = WingPlanform::from_params(params);
+        let surfaces = surface_placements(params)
+            .into_iter()
+            .map(|placement| LiftingSurface {
+                wing: wing.scaled(placement.scale),
+                x_offset: placement.x_offset,
+                z_offset: placement.z_offset,
+                incidence: placement.incidence,
+            })
+            .collect();
+        Self { surfaces }
+    }
+
+    pub fn area(&self) -> f32 {
+        self.surfaces.iter().map(LiftingSurface::area).sum()
+    }
+
+    pub fn span(&self) -> f32 {
+        self.surfaces.iter().map(LiftingSurface::span).fold(0.0, f32::max)
+    }
+}
+
+type Vector = [f32; 3];
+
+fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Vector, b: Vector) -> Vector {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot(a: Vector, b: Vector) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn norm(a: Vector) -> f32 {
+    dot(a, a).sqrt()
+}
+
+// Biot-Savart for a unit-strength straight segment from a to b
+fn segment_velocity(point: Vector, a: Vector, b: Vector) -> Vector {
+    let (r1, r2) = (sub(point, a), sub(point, b));
+    let normal = cross(r1, r2);
+    let normal_squared = dot(normal, normal);
+    let (length1, length2) = (norm(r1), norm(r2));
+    if normal_squared < CORE_RADIUS.powi(2) || length1 < CORE_RADIUS || length2 < CORE_RADIUS {
+        return [0.0; 3];
+    }
+    let r0 = sub(b, a);
+    let scale = (dot(r0, r1) / length1 - dot(r0, r2) / length2) / (4.0 * PI * normal_squared);
+    [normal[0] * scale, normal[1] * scale, normal[2] * scale]
+}
+
+// Unit-strength semi-infinite line starting at a and running downstream along +x
+fn trailing_velocity(point: Vector, a: Vector) -> Vector {
+    let r = sub(point, a);
+    let normal = cross([1.0, 0.0, 0.0], r);
+    let distance_squared = dot(normal, normal);
+    if distance_squared < CORE_RADIUS.powi(2) {
+        return [0.0; 3];
+    }
+    let scale = (1.0 + r[0] / norm(r)) / (4.0 * PI * distance_squared);
+    [normal[0] * scale, normal[1] * scale, normal[2] * scale]
+}
+
+struct Strip {
+    surface: usize,
+    left: Vector,  // Bound vortex ends on the quarter chord
+    right: Vector,
+    control: Vector,  // Three-quarter chord
+    normal: Vector,
+    chord: f32,
+    incidence: f32,  // rad, from twist and decalage
+    dihedral: f32,  // rad, signed so the panel rises towards its own tip
+}
+
+impl Strip {
+    fn horseshoe_velocity(&self, point: Vector) -> Vector {
+        let bound = segment_velocity(point, self.left, self.right);
+        let (right_leg, left_leg) = (trailing_velocity(point, self.right), trailing_velocity(point, self.left));
+        [
+            bound[0] + right_leg[0] - left_leg[0],
+            bound[1] + right_leg[1] - left_leg[1],
+            bound[2] + right_leg[2] - left_leg[2],
+        ]
+    }
+
+    fn width(&self) -> f32 {
+        self.right[1] - self.left[1]
+    }
+
+    // Trailing-edge width seen in the Trefftz plane, ignoring sweep
+    fn length(&self) -> f32 {
+        (self.right[1] - self.left[1]).hypot(self.right[2] - self.left[2])
+    }
+}
+
+fn build_strips(planform: &Planform) -> Vec<Strip> {
+    let mut strips = Vec::new();
+    for (index, surface) in planform.surfaces.iter().enumerate() {
+        // Cosine spacing across the whole span; panel breaks fall between nodes
+        let strips_per_surface = 2 * STRIPS_PER_PANEL * surface.wing.panels.len().max(1);
+        let semispan = 0.5 * surface.span();
+        let position = |y: f32| {
+            let station = surface.wing.station(y.abs());
+            [surface.x_offset + station.x, y, surface.z_offset + station.z]
+        };
+        let node = |k: usize| position(-semispan * (PI * k as f32 / strips_per_surface as f32).cos());
+        for k in 0..strips_per_surface {
+            let (left, right) = (node(k), node(k + 1));
+            // Control points at the semicircle midpoints keep the tip strips well conditioned
+            let y = -semispan * (PI * (k as f32 + 0.5) / strips_per_surface as f32).cos();
+            let station = surface.wing.station(y.abs());
+            let incidence = (surface.incidence - station.washout).to_radians();
+            let dihedral = station.dihedral.to_radians();
+            let dihedral = if y >= 0.0 { dihedral } else { -dihedral };
+            let quarter_chord = position(y);
+            strips.push(Strip {
+                surface: index,
+                left,
+                right,
+                control: [quarter_chord[0] + 0.5 * station.chord, y, quarter_chord[2]],
+                normal: [
+                    incidence.sin() * dihedral.cos(),
+                    -incidence.cos() * dihedral.sin(),
+                    incidence.cos() * dihedral.cos(),
+                ],
+                chord: station.chord,
+                incidence,
+                dihedral,
+            });
+        }
+    }
+    strips
+}
+
+thread_local! {
+    // A solve costs far more than the rest of an evaluation and the planform rarely changes between calls
+    static LAST_SOLUTION: RefCell<Option<SpanLoading>> = const { RefCell::new(None) };
+}
+
+impl SpanLoading {
+    pub fn from_params(params: &FlightParams) -> Self {
+        let planform = Planform::from_params(params);
+        LAST_SOLUTION.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if let Some(solution) = cache.as_ref().filter(|solution| solution.planform == planform) {
+                return solution.clone();
+            }
+            let solution = Self::solve(planform);
+            *cache = Some(solution.clone());
+            solution
+        })
+    }
+
+    pub fn solve(planform: Planform) -> Self {
+        let strips = build_strips(&planform);
+        let reference_area = planform.area().max(1e-6);
+        let reference_span = planform.span();
+
+        // Flow tangency at each control point, per unit freestream
+        let influence: Vec<Vec<f32>> = strips
+            .iter()
+            .map(|receiver| strips.iter().map(|source| dot(source.horseshoe_velocity(receiver.control), receiver.normal)).collect())
+            .collect();
+        // Freestream (cos α, 0, sin α) splits into a part proportional to sin α and a twist part at cos α ≈ 1
+        let alpha_rhs = strips.iter().map(|strip| -strip.incidence.cos() * strip.dihedral.cos()).collect();
+        let twist_rhs = strips.iter().map(|strip| -strip.incidence.sin() * strip.dihedral.cos()).collect();
+        let solution = solve_linear(influence, vec![alpha_rhs, twist_rhs]);
+        let (alpha_circulation, twist_circulation) = (&solution[0], &solution[1]);
+
+        // Kutta-Joukowski with unit freestream: CL = 2ΣΓΔy / S
+        let lift = |circulation: &[f32]| {
+            2.0 * strips.iter().zip(circulation).map(|(strip, gamma)| gamma * strip.width()).sum::<f32>() / reference_area
+        };
+        let lift_slope = lift(alpha_circulation);
+        let zero_alpha_lift = lift(twist_circulation);
+
+        // Trefftz plane: trailing legs become 2D point vortices; normalwash at the control stations
+        let trefftz: Vec<Vec<f32>> = strips
+            .iter()
+            .map(|receiver| {
+                let point = [receiver.control[1], receiver.control[2]];
+                let normal = [-receiver.dihedral.sin(), receiver.dihedral.cos()];
+                strips
+                    .iter()
+                    .map(|source| {
+                        let point_vortex = |position: Vector, strength: f32| {
+                            let (dy, dz) = (point[0] - position[1], point[1] - position[2]);
+                            let distance_squared = dy * dy + dz * dz;
+                            if distance_squared < CORE_RADIUS.powi(2) {
+                                return 0.0;
+                            }
+                            strength * (-dz * normal[0] + dy * normal[1]) / (2.0 * PI * distance_squared)
+                        };
+                        point_vortex(source.right, 1.0) + point_vortex(source.left, -1.0)
+                    })
+                    .collect()
+            })
+            .collect();
+        let drag = |a: &[f32], b: &[f32]| {
+            -strips
+                .iter()
+                .enumerate()
+                .map(|(i, strip)| {
+                    let normalwash: f32 = trefftz[i].iter().zip(b).map(|(t, gamma)| t * gamma).sum();
+                    a[i] * strip.length() * normalwash
+                })
+                .sum::<f32>()
+                / reference_area
+        };
+        let induced_drag_terms = [
+            drag(twist_circulation, twist_circulation),
+            drag(alpha_circulation, twist_circulation) + drag(twist_circulation, alpha_circulation),
+            drag(alpha_circulation, alpha_circulation),
+        ];
+
+        let stations = strips
+            .iter()
+            .zip(alpha_circulation.iter().zip(twist_circulation))
+            .map(|(strip, (alpha_gamma, twist_gamma))| {
+                let additional = 2.0 * alpha_gamma / (strip.chord * lift_slope.max(1e-6));
+                SpanStation {
+                    surface: strip.surface,
+                    y: strip.control[1],
+                    chord: strip.chord,
+                    width: strip.width(),
+                    additional,
+                    basic: 2.0 * twist_gamma / strip.chord - additional * zero_alpha_lift,
+                }
+            })
+            .collect();
+
+        Self {
+            planform,
+            reference_area,
+            reference_span,
+            aspect_ratio: reference_span.powi(2) / reference_area,
+            lift_slope,
+            zero_alpha_lift,
+            stations,
+            induced_drag_terms,
+        }
+    }
+
+    pub fn induced_drag_coefficient(&self, cl: f32) -> f32 {
+        let s = (cl - self.zero_alpha_lift) / self.lift_slope.max(1e-6);
+        let [k0, k1, k2] = self.induced_drag_terms;
+        (k0 + k1 * s + k2 * s * s).max(0.0)
+    }
+
+    // e = CL²/(π·AR·CDi); at zero lift the additional loading alone sets it
+    pub fn span_efficiency(&self, cl: f32) -> f32 {
+        let induced_drag = self.induced_drag_coefficient(cl);
+        if cl.abs() < 0.05 || induced_drag <= 0.0 {
+            let additional_drag = self.induced_drag_terms[2] / self.lift_slope.max(1e-6).powi(2);
+            return 1.0 / (PI * self.aspect_ratio * additional_drag.max(1e-9));
+        }
+        cl.powi(2) / (PI * self.aspect_ratio * induced_drag)
+    }
+
+    // Aspect ratio of the single flat elliptic wing with the same lift slope, from Helmbold's relation
+    pub fn effective_aspect_ratio(&self) -> f32 {
+        let ratio = 2.0 * PI / self.lift_slope.max(1e-6);
+        let helmbold = (ratio.powi(2) - 1.0).max(1e-6) / (2.0 * ratio);
+        2.0 / helmbold
+    }
+
+    pub fn local_lift_coefficient(&self, station: &SpanStation, cl: f32) -> f32 {
+        station.basic + station.additional * cl
+    }
+
+    // Share of the total lift carried by each surface
+    pub fn surface_lift_fractions(&self, cl: f32) -> Vec<f32> {
+        let mut fractions = vec![0.0; self.planform.surfaces.len()];
+        for station in &self.stations {
+            fractions[station.surface] += self.local_lift_coefficient(station, cl) * station.chord * station.width;
+        }
+        let total: f32 = fractions.iter().sum();
+        if total.abs() > 1e-6 {
+            fractions.iter_mut().for_each(|fraction| *fraction /= total);
+        }
+        fractions
+    }
+}